@@ -0,0 +1,51 @@
+//! Generates the registry of solutions for the main binary.
+//! With the `registry` feature, every `src/bin/NN.rs` is included as a module of the main binary, so
+//! `all --in-process` can call its solution directly. Without it, the registry is empty.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    let mut days: Vec<u8> = vec![];
+
+    if env::var_os("CARGO_FEATURE_REGISTRY").is_some() {
+        for entry in fs::read_dir(Path::new(&manifest_dir).join("src/bin")).unwrap() {
+            let path = entry.unwrap().path();
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let is_day = path.extension().is_some_and(|e| e == "rs")
+                && stem.len() == 2
+                && stem.chars().all(|c| c.is_ascii_digit());
+            if let (true, Ok(day)) = (is_day, stem.parse()) {
+                days.push(day);
+            }
+        }
+    }
+
+    days.sort_unstable();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for day in days {
+        let path = Path::new(&manifest_dir).join(format!("src/bin/{day:02}.rs"));
+        modules.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code)]\n#[path = {:?}]\nmod day{day:02};\n",
+            path.display().to_string()
+        ));
+        entries.push_str(&format!(
+            "    #[cfg(not(test))]\n    Solution {{ day: advent_of_code::day!({day}), run: day{day:02}::__run }},\n"
+        ));
+    }
+
+    let registry = format!(
+        "// @generated by build.rs\n{modules}\npub const SOLUTIONS: &[Solution] = &[\n{entries}];\n"
+    );
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
@@ -58,9 +58,7 @@ pub fn part_two(input: &str) -> Option<usize> {
 
 pub fn get_game_id(line: &str) -> Option<usize> {
     let re = Regex::new(r"^Game\s(\d+):\s(.*)").unwrap();
-    let Some(groups) = re.captures(line) else {
-        return None;
-    };
+    let groups = re.captures(line)?;
     let game_id: &str = groups.get(1).map_or("", |m| m.as_str());
     Some(game_id.parse::<usize>().unwrap())
 }
@@ -1,4 +1,10 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(
+    4,
+    parse,
+    variants {
+        2: [part_two, part_two_counting]
+    }
+);
 
 use regex::Regex;
 use std::{collections::VecDeque, fmt};
@@ -32,13 +38,7 @@ impl fmt::Debug for CardScore {
     }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let mut cards: Vec<Card> = vec![];
-
-    for line in input.lines() {
-        cards.push(parse_card(line));
-    }
-
+pub fn part_one(cards: &[Card]) -> Option<usize> {
     let mut total = 0;
     for card in cards {
         let score = score_card(card);
@@ -48,16 +48,28 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(total)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let mut cards: Vec<Card> = vec![];
+pub fn part_two(cards: &[Card]) -> Option<usize> {
+    let copies = copy_cards(seed_copies(cards));
 
-    for line in input.lines() {
-        cards.push(parse_card(line));
+    Some(copies.len())
+}
+
+// instead of copying cards, keep track of how many of each card we have.
+pub fn part_two_counting(cards: &[Card]) -> Option<usize> {
+    let mut counts = vec![1; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let hits = matching_hits(card);
+        for next in (i + 1)..usize::min(i + 1 + hits, cards.len()) {
+            counts[next] += counts[i];
+        }
     }
 
-    let copies = copy_cards(seed_copies(cards));
+    Some(counts.iter().sum())
+}
 
-    Some(copies.len())
+pub fn parse(input: &str) -> Vec<Card> {
+    input.lines().map(parse_card).collect()
 }
 
 pub fn parse_card(line: &str) -> Card {
@@ -99,7 +111,7 @@ pub fn cleanup_numbers(garbage: &str) -> Vec<usize> {
         .collect()
 }
 
-pub fn score_card(card: Card) -> usize {
+pub fn score_card(card: &Card) -> usize {
     let hits = matching_hits(card);
 
     // to avoid raising the score to ^0
@@ -112,7 +124,7 @@ pub fn score_card(card: Card) -> usize {
 }
 
 // what numbers match between winners and choices on a card
-pub fn matching_hits(card: Card) -> usize {
+pub fn matching_hits(card: &Card) -> usize {
     let hits: Vec<usize> = card
         .choices
         .iter()
@@ -122,7 +134,7 @@ pub fn matching_hits(card: Card) -> usize {
     hits.len()
 }
 
-pub fn seed_copies(cards: Vec<Card>) -> Vec<CardScore> {
+pub fn seed_copies(cards: &[Card]) -> Vec<CardScore> {
     let mut scores: Vec<CardScore> = vec![];
     for card in cards {
         scores.push(CardScore {
@@ -167,13 +179,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(30));
     }
 
@@ -206,7 +222,7 @@ mod tests {
             winners: vec![1, 2, 3, 4],
             choices: vec![2, 3],
         };
-        let result = matching_hits(card);
+        let result = matching_hits(&card);
 
         let expected = 2;
         assert_eq!(result, expected);
@@ -225,7 +241,7 @@ mod tests {
             choices: vec![2, 3],
         };
         let cards = vec![card1, card2];
-        let result = seed_copies(cards);
+        let result = seed_copies(&cards);
 
         let expected: Vec<CardScore> =
             vec![CardScore { id: 1, score: 4 }, CardScore { id: 2, score: 2 }];
@@ -1,8 +1,8 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse);
 
+use advent_of_code::template::progress::Progress;
 use itertools::Itertools;
 use regex::Regex;
-use std::usize;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct RangeMap {
@@ -23,12 +23,10 @@ pub struct Almanac {
     humidity_to_location: Vec<RangeMap>,
 }
 
-pub fn part_one(input: &str) -> Option<isize> {
-    let almanac = parse(input);
-
+pub fn part_one(almanac: &Almanac) -> Option<isize> {
     let mut locations = vec![];
 
-    for seed in almanac.seeds {
+    for &seed in &almanac.seeds {
         let seed = seed as isize;
         let soil = lookup(seed, almanac.seed_to_soil.clone());
         let fert = lookup(soil, almanac.soil_to_fertilizer.clone());
@@ -47,18 +45,17 @@ pub fn part_one(input: &str) -> Option<isize> {
 }
 
 // this might be the slowest code I have ever written
-pub fn part_two(input: &str) -> Option<isize> {
-    let almanac = parse(input);
-
-    let seeds_ranges = part_two_seeds(almanac.seeds);
+pub fn part_two(almanac: &Almanac) -> Option<isize> {
+    let seeds_ranges = part_two_seeds(almanac.seeds.clone());
 
     let mut min = isize::MAX;
 
-    let mut modulo_counter = 1;
+    let total = seeds_ranges.iter().map(|seeds| seeds.len() as u64).sum();
+    let progress = Progress::new(total);
 
-    for seeds in seeds_ranges {
-        println!("starting seed range: {}", seeds.len());
-        for seed in seeds {
+    for (i, seeds) in seeds_ranges.iter().enumerate() {
+        progress.message(format!("seed range {}/{}", i + 1, seeds_ranges.len()));
+        for &seed in seeds {
             let seed = seed as isize;
             let soil = lookup(seed, almanac.seed_to_soil.clone());
             let fert = lookup(soil, almanac.soil_to_fertilizer.clone());
@@ -68,16 +65,15 @@ pub fn part_two(input: &str) -> Option<isize> {
             let humi = lookup(temp, almanac.temperature_to_humidity.clone());
             let locn = lookup(humi, almanac.humidity_to_location.clone());
 
-            if modulo_counter % 1_000_000 == 0 {
-                modulo_counter = 1;
-                print!(".");
-            } else {
-                modulo_counter += 1;
-            }
+            progress.tick();
 
             if locn < min {
                 min = locn;
-                println!("New min: {}", min);
+                progress.message(format!(
+                    "seed range {}/{}, min {min}",
+                    i + 1,
+                    seeds_ranges.len()
+                ));
             }
         }
     }
@@ -120,9 +116,9 @@ pub fn lookup(seed: isize, ranges: Vec<RangeMap>) -> isize {
     {
         Some(range) => {
             let offset: isize = range.destination as isize - range.source as isize;
-            (seed + offset).try_into().unwrap()
+            seed + offset
         }
-        None => seed.try_into().unwrap(),
+        None => seed,
     }
 }
 
@@ -208,13 +204,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", DAY,
+        )));
         assert_eq!(result, Some(46));
     }
 
@@ -1,10 +1,19 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, sync_tests,
+};
 use args::{parse, AppArguments};
 
+/// Solutions compiled into this binary with the `registry` feature, see `build.rs`.
+mod solutions {
+    use advent_of_code::template::registry::Solution;
+
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use std::process;
+    use std::{process, time::Duration};
 
-    use advent_of_code::Day;
+    use advent_of_code::{template::runner::BenchConfig, Day};
 
     pub enum AppArguments {
         Download {
@@ -12,19 +21,43 @@ mod args {
         },
         Read {
             day: Day,
+            refresh: bool,
+            new: bool,
+        },
+        Examples {
+            day: Day,
+            overwrite: bool,
         },
         Scaffold {
             day: Day,
         },
+        SyncTests {
+            day: Day,
+        },
         Solve {
             day: Day,
             release: bool,
             time: bool,
+            bench: BenchConfig,
+            alloc: bool,
             submit: Option<u8>,
+            input: Option<String>,
+            example: bool,
+            example_part: Option<u8>,
+            force_submit: bool,
         },
         All {
             release: bool,
             time: bool,
+            alloc: bool,
+            compare: bool,
+            threshold: f64,
+            timeout: Option<Duration>,
+            part_timeout: Option<Duration>,
+            in_process: bool,
+            jobs: usize,
+            bench: BenchConfig,
+            verify: bool,
         },
     }
 
@@ -35,21 +68,46 @@ mod args {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                alloc: args.contains("--alloc"),
+                compare: args.contains("--compare"),
+                threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+                timeout: opt_secs(&mut args, "--timeout")?,
+                part_timeout: opt_secs(&mut args, "--part-timeout")?,
+                in_process: args.contains("--in-process"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+                bench: parse_bench_config(&mut args)?,
+                verify: args.contains("--verify"),
             },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
+                refresh: args.contains("--refresh"),
+                new: args.contains("--new"),
+            },
+            Some("examples") => AppArguments::Examples {
+                day: args.free_from_str()?,
+                overwrite: args.contains("--overwrite"),
             },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
             },
+            Some("sync-tests") => AppArguments::SyncTests {
+                day: args.free_from_str()?,
+            },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 time: args.contains("--time"),
+                bench: parse_bench_config(&mut args)?,
+                alloc: args.contains("--alloc"),
+                input: args.opt_value_from_str("--input")?,
+                force_submit: args.contains("--force-submit"),
+                example: args.contains("--example"),
+                // the part of `--example <part>` is optional, so it is read as a free argument once all options are consumed.
+                example_part: args.opt_free_from_str()?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -68,6 +126,31 @@ mod args {
 
         Ok(app_args)
     }
+
+    fn parse_bench_config(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BenchConfig, Box<dyn std::error::Error>> {
+        Ok(BenchConfig::new(
+            opt_secs(args, "--bench-time")?,
+            args.opt_value_from_str("--min-samples")?,
+            args.opt_value_from_str("--max-samples")?,
+            opt_secs(args, "--warmup")?,
+        ))
+    }
+
+    /// Parses an optional duration in seconds, rejecting negative and non-finite values.
+    fn opt_secs(
+        args: &mut pico_args::Arguments,
+        key: &'static str,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        let value: Option<f64> = args.opt_value_from_str(key)?;
+        value
+            .map(|secs| {
+                Duration::try_from_secs_f64(secs)
+                    .map_err(|e| format!("invalid value for {key}: {secs} ({e})").into())
+            })
+            .transpose()
+    }
 }
 
 fn main() {
@@ -77,16 +160,64 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
+            AppArguments::All {
+                release,
+                time,
+                alloc,
+                compare,
+                threshold,
+                timeout,
+                part_timeout,
+                in_process,
+                jobs,
+                bench,
+                verify,
+            } => all::handle(
+                &all::Options {
+                    is_release: release,
+                    is_timed: time,
+                    is_alloc: alloc,
+                    compare,
+                    threshold,
+                    timeout,
+                    part_timeout,
+                    in_process,
+                    jobs,
+                    bench,
+                    verify,
+                },
+                solutions::SOLUTIONS,
+            ),
             AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::Read { day, refresh, new } => read::handle(day, refresh, new),
+            AppArguments::Examples { day, overwrite } => examples::handle(day, overwrite),
             AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::SyncTests { day } => sync_tests::handle(day),
             AppArguments::Solve {
                 day,
                 release,
                 time,
+                bench,
+                alloc,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                input,
+                example,
+                example_part,
+                force_submit,
+            } => solve::handle(
+                day,
+                &solve::Options {
+                    is_release: release,
+                    is_timed: time,
+                    bench,
+                    is_alloc: alloc,
+                    submit_part: submit,
+                    input,
+                    example,
+                    example_part,
+                    force_submit,
+                },
+            ),
         },
     };
 }
@@ -0,0 +1,153 @@
+/// A counting global allocator, installed in every binary that links this crate.
+/// Counting is disabled unless the runner explicitly tracks a closure, so regular and benched runs
+/// only pay for a single relaxed atomic load per allocation.
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+pub struct CountingAllocator;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+impl CountingAllocator {
+    fn record(allocated: usize, freed: usize) {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if allocated > 0 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(allocated, Ordering::Relaxed);
+        }
+
+        #[allow(clippy::cast_possible_wrap)]
+        let delta = allocated as isize - freed as isize;
+        let live = LIVE.fetch_add(delta, Ordering::Relaxed) + delta;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage of a single tracked execution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    /// Number of allocations (including reallocations).
+    pub allocations: usize,
+    /// Total number of bytes requested.
+    pub bytes: usize,
+    /// Highest number of bytes live at the same time, relative to the start of the execution.
+    pub peak: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Runs `func` with allocation counting enabled and returns its result alongside the heap usage.
+pub fn track<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    LIVE.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+
+    ENABLED.store(true, Ordering::SeqCst);
+    let result = func();
+    ENABLED.store(false, Ordering::SeqCst);
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        #[allow(clippy::cast_sign_loss)]
+        peak: PEAK.load(Ordering::Relaxed).max(0) as usize,
+    };
+
+    (result, stats)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, AllocStats};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = AllocStats {
+            allocations: 12,
+            bytes: 2048,
+            peak: 800,
+        };
+        assert_eq!(stats.to_string(), "12 allocs, 2.0 KiB, peak 800 B");
+    }
+}
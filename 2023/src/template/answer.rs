@@ -0,0 +1,255 @@
+/// The answer to a part. Solutions can return any type that converts into it: integers up to `i128`/`u128`,
+/// strings and pixel grids (`Vec<Vec<bool>>`, `true` being a lit pixel).
+/// Answers are normalized on conversion, so equal answers compare equal regardless of how they were built:
+/// non-negative integers are always unsigned, text is trimmed (multi-line text only at line ends), and
+/// multi-line text drawn with `#` and `.` is read as pixels. Text written like an integer is read as one, and a
+/// pixel grid with a single row is read as text.
+use std::fmt::Display;
+
+use crate::template::ocr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// A negative integer.
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+    Pixels(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    /// Whether the answer spans multiple lines when printed.
+    #[must_use]
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Text(text) => text.contains('\n'),
+            Answer::Pixels(_) => true,
+            Answer::Signed(_) | Answer::Unsigned(_) => false,
+        }
+    }
+
+    /// The letters a multi-line answer draws, if it is made up of known block letters.
+    #[must_use]
+    pub fn letters(&self) -> Option<String> {
+        match self {
+            Answer::Pixels(rows) => ocr::recognize(rows),
+            Answer::Text(text) if text.contains('\n') => ocr::recognize_str(text),
+            _ => None,
+        }
+    }
+
+    /// The value to submit to Advent of Code. Multi-line answers are never submitted as they are,
+    /// only the letters they draw, so this is `None` for drawings that can't be read.
+    #[must_use]
+    pub fn submission(&self) -> Option<String> {
+        if self.is_multiline() {
+            self.letters()
+        } else {
+            Some(self.to_string())
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Pixels(rows) => {
+                let lines: Vec<String> = rows
+                    .iter()
+                    .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+                    .collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+        }
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Unsigned(value.into())
+            }
+        })*
+    };
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                let value = i128::from(value);
+                u128::try_from(value).map_or(Answer::Signed(value), Answer::Unsigned)
+            }
+        })*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, u128);
+from_signed!(i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        (value as i128).into()
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        text.as_str().into()
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        // leading spaces are kept on multi-line text, they are part of drawings made of `█` and spaces.
+        let lines: Vec<&str> = text
+            .lines()
+            .map(str::trim_end)
+            .skip_while(|line| line.is_empty())
+            .collect();
+        let end = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .map_or(0, |i| i + 1);
+
+        let text = match &lines[..end] {
+            [line] => line.trim_start().to_string(),
+            lines => lines.join("\n"),
+        };
+
+        // integers given as text, e.g. from `format!`, compare equal to the integer itself.
+        if let Some(value) = parse_integer(&text) {
+            return value;
+        }
+
+        let is_drawing = text.contains('\n') && text.chars().all(|c| matches!(c, '#' | '.' | '\n'));
+
+        if is_drawing {
+            Answer::Pixels(
+                text.lines()
+                    .map(|line| line.chars().map(|c| c == '#').collect())
+                    .collect(),
+            )
+        } else {
+            Answer::Text(text)
+        }
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        c.to_string().into()
+    }
+}
+
+impl From<Vec<Vec<bool>>> for Answer {
+    fn from(rows: Vec<Vec<bool>>) -> Self {
+        // a single row is not a drawing, it is normalized like the same row given as text.
+        if rows.len() < 2 {
+            return Answer::Pixels(rows).to_string().into();
+        }
+        Answer::Pixels(rows)
+    }
+}
+
+/// Reads text that is written exactly like an integer, so `"007"` or `"+7"` stay text.
+fn parse_integer(text: &str) -> Option<Answer> {
+    if let Ok(value) = text.parse::<i128>() {
+        return (value.to_string() == text).then(|| value.into());
+    }
+    let value = text.parse::<u128>().ok()?;
+    (value.to_string() == text).then(|| value.into())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn normalizes_integers() {
+        assert_eq!(Answer::from(42_u8), Answer::Unsigned(42));
+        assert_eq!(Answer::from(42_isize), Answer::Unsigned(42));
+        assert_eq!(Answer::from(-42_i64), Answer::Signed(-42));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(i128::MIN).to_string(), i128::MIN.to_string());
+    }
+
+    #[test]
+    fn normalizes_text() {
+        assert_eq!(
+            Answer::from("  CFLELOYFCS\n"),
+            Answer::Text("CFLELOYFCS".into())
+        );
+        assert_eq!(Answer::from("a  \r\nb\n\n").to_string(), "a\nb");
+    }
+
+    #[test]
+    fn reads_integer_text_as_integers() {
+        assert_eq!(Answer::from("42\n"), Answer::from(42_u32));
+        assert_eq!(Answer::from(String::from("-42")), Answer::from(-42_i64));
+        assert_eq!(Answer::from(u128::MAX.to_string()), Answer::from(u128::MAX));
+        assert_eq!(Answer::from("007"), Answer::Text("007".into()));
+        assert_eq!(Answer::from("+7"), Answer::Text("+7".into()));
+    }
+
+    #[test]
+    fn reads_single_rows_as_text() {
+        assert_eq!(Answer::from(vec![vec![true, false]]), Answer::from("#."));
+        assert!(!Answer::from(vec![vec![true, false]]).is_multiline());
+    }
+
+    #[test]
+    fn reads_drawings_as_pixels() {
+        let answer = Answer::from("\n#..#\n.##.\n");
+        assert_eq!(
+            answer,
+            Answer::Pixels(vec![
+                vec![true, false, false, true],
+                vec![false, true, true, false]
+            ])
+        );
+        assert_eq!(answer.to_string(), "#..#\n.##.");
+        assert_eq!(
+            answer,
+            Answer::from(vec![
+                vec![true, false, false, true],
+                vec![false, true, true, false]
+            ])
+        );
+    }
+
+    #[test]
+    fn submits_single_line_answers_only() {
+        assert_eq!(Answer::from(-7_i32).submission(), Some("-7".into()));
+        assert_eq!(Answer::from("abc").submission(), Some("abc".into()));
+        assert_eq!(Answer::from("a\nb").submission(), None);
+        assert_eq!(
+            Answer::from(vec![vec![true], vec![false]]).submission(),
+            None
+        );
+    }
+
+    #[test]
+    fn submits_letters_of_drawings() {
+        let drawing = "#..#.####\n#..#.#...\n####.###.\n#..#.#...\n#..#.#...\n#..#.####";
+        assert_eq!(Answer::from(drawing).submission(), Some("HE".into()));
+        assert_eq!(
+            Answer::from(drawing.replace('#', "█").replace('.', " ")).submission(),
+            Some("HE".into())
+        );
+
+        // the first row of `A` starts with an unlit pixel.
+        let drawing = " ██  ███ \n█  █ █  █\n█  █ ███ \n████ █  █\n█  █ █  █\n█  █ ███ \n";
+        assert_eq!(Answer::from(drawing).submission(), Some("AB".into()));
+    }
+}
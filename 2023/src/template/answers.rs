@@ -0,0 +1,273 @@
+/// Module that stores accepted answers and checks new results against them.
+/// Answers are kept as salted SHA-256 hashes in `data/answers.tsv`, so they are not stored in plain text.
+/// Each entry is keyed by day, part and a hash of the input the answer belongs to.
+use std::{
+    collections::hash_map::RandomState,
+    fmt::Write as _,
+    fs,
+    hash::{BuildHasher, Hasher},
+    io,
+    path::Path,
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::Day;
+
+static PATH: &str = "data/answers.tsv";
+static HEADER: &str = "day\tpart\tinput\tsalt\thash";
+
+/// Hash of the input the current solution runs against, see [`set_input`].
+static INPUT: Mutex<Option<String>> = Mutex::new(None);
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Outcome of checking an answer against the store.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    /// The answer matches the accepted one.
+    Verified,
+    /// The answer differs from the accepted one.
+    Regressed,
+    /// No answer was accepted for this day, part and input yet.
+    Unknown,
+}
+
+impl Verdict {
+    #[must_use]
+    pub fn mark(&self) -> &'static str {
+        match self {
+            Verdict::Verified => "✔",
+            Verdict::Regressed => "✖",
+            Verdict::Unknown => "?",
+        }
+    }
+}
+
+/// An accepted answer.
+#[derive(Clone, Debug, PartialEq)]
+struct Entry {
+    day: Day,
+    part: u8,
+    /// Hash of the input.
+    input: String,
+    salt: String,
+    /// Hash of the salted answer.
+    hash: String,
+}
+
+impl Entry {
+    fn new(day: Day, part: u8, input: &str, answer: &str) -> Self {
+        let salt = random_salt();
+        Self {
+            day,
+            part,
+            input: input.into(),
+            hash: hash_answer(&salt, answer),
+            salt,
+        }
+    }
+
+    fn matches(&self, answer: &str) -> bool {
+        self.hash == hash_answer(&self.salt, answer)
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}",
+            self.day, self.part, self.input, self.salt, self.hash
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self, Error> {
+        let error = || Error::Parser(format!("Malformed answer entry: {line}"));
+        let fields: Vec<&str> = line.split('\t').collect();
+
+        let [day, part, input, salt, hash] = fields[..] else {
+            return Err(error());
+        };
+
+        Ok(Self {
+            day: day.parse().map_err(|_| error())?,
+            part: part.parse().map_err(|_| error())?,
+            input: input.into(),
+            salt: salt.into(),
+            hash: hash.into(),
+        })
+    }
+}
+
+/// Sets the input that answers of the current run are checked against.
+pub fn set_input(input: &str) {
+    *INPUT.lock().unwrap() = Some(hash_input(input));
+}
+
+/// Checks an answer for a part of the current input against the store.
+#[must_use]
+pub fn verify(day: Day, part: u8, answer: &str) -> Verdict {
+    let Some(input) = INPUT.lock().unwrap().clone() else {
+        return Verdict::Unknown;
+    };
+
+    match load() {
+        Ok(entries) => check(&entries, day, part, &input, answer),
+        Err(_) => {
+            eprintln!("Failed to read {PATH}, answers are not verified.");
+            Verdict::Unknown
+        }
+    }
+}
+
+/// Stores an answer for a part of the current input as accepted, replacing a previous one.
+pub fn record(day: Day, part: u8, answer: &str) -> Result<(), Error> {
+    let Some(input) = INPUT.lock().unwrap().clone() else {
+        return Ok(());
+    };
+
+    let mut entries = load()?;
+    entries.retain(|e| !(e.day == day && e.part == part && e.input == input));
+    entries.push(Entry::new(day, part, &input, answer));
+    entries.sort_by_key(|e| (e.day, e.part));
+
+    let mut content = format!("{HEADER}\n");
+    for entry in &entries {
+        content.push_str(&entry.to_line());
+        content.push('\n');
+    }
+
+    fs::write(PATH, content)?;
+    Ok(())
+}
+
+fn check(entries: &[Entry], day: Day, part: u8, input: &str, answer: &str) -> Verdict {
+    match entries
+        .iter()
+        .find(|e| e.day == day && e.part == part && e.input == input)
+    {
+        Some(entry) if entry.matches(answer) => Verdict::Verified,
+        Some(_) => Verdict::Regressed,
+        None => Verdict::Unknown,
+    }
+}
+
+fn load() -> Result<Vec<Entry>, Error> {
+    if !Path::new(PATH).exists() {
+        return Ok(vec![]);
+    }
+
+    parse_content(&fs::read_to_string(PATH)?)
+}
+
+fn parse_content(s: &str) -> Result<Vec<Entry>, Error> {
+    s.lines()
+        .filter(|line| !line.is_empty() && *line != HEADER)
+        .map(Entry::from_line)
+        .collect()
+}
+
+fn hash_input(input: &str) -> String {
+    // editors and downloads disagree on trailing newlines, they don't make a different input.
+    hex(&sha256(input.trim_end().as_bytes()))
+}
+
+fn hash_answer(salt: &str, answer: &str) -> String {
+    hex(&sha256(format!("{salt}:{}", answer.trim()).as_bytes()))
+}
+
+/// A nonce that keeps equal answers from hashing the same. It is not secret and needs no cryptographic randomness,
+/// the salt is stored next to the hash.
+fn random_salt() -> String {
+    let mut salt = String::new();
+    for _ in 0..2 {
+        let value = RandomState::new().build_hasher().finish();
+        let _ = write!(salt, "{value:016x}");
+    }
+    salt
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{b:02x}");
+        s
+    })
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, hex, parse_content, sha256, Entry, Verdict};
+    use crate::day;
+
+    #[test]
+    fn hashes_sha256() {
+        assert_eq!(
+            hex(&sha256(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(&sha256(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex(&sha256(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn checks_answers() {
+        let entries = vec![Entry::new(day!(5), 1, "input", "35")];
+
+        assert_eq!(
+            check(&entries, day!(5), 1, "input", "35"),
+            Verdict::Verified
+        );
+        assert_eq!(
+            check(&entries, day!(5), 1, "input", "36"),
+            Verdict::Regressed
+        );
+        assert_eq!(check(&entries, day!(5), 2, "input", "35"), Verdict::Unknown);
+        assert_eq!(check(&entries, day!(5), 1, "other", "35"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn does_not_store_raw_answers() {
+        let entry = Entry::new(day!(5), 1, "input", "123456789");
+        assert!(!entry.to_line().contains("123456789"));
+        assert_ne!(
+            entry.hash,
+            Entry::new(day!(5), 1, "input", "123456789").hash
+        );
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = Entry::new(day!(5), 2, "input", "46");
+        let parsed = parse_content(&format!("{}\n{}\n", super::HEADER, entry.to_line())).unwrap();
+        assert_eq!(parsed, vec![entry]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_on_malformed_entry() {
+        parse_content("05\t1\tinput").unwrap();
+    }
+}
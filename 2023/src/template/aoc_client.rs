@@ -0,0 +1,526 @@
+/// Client for the Advent of Code website, used to download inputs and puzzles and to submit answers.
+/// The session cookie is read from `AOC_SESSION`, or from the file at `AOC_SESSION_FILE`, falling back to
+/// `~/.adventofcode.session` (the file aoc-cli uses). Requests are throttled across processes, and
+/// `AOC_BASE_URL` points the client at another server, e.g. a local stub. Requests identify their sender with
+/// `AOC_USER_AGENT`, or with `repository` from Cargo.toml.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+
+use crate::template::markdown;
+use crate::Day;
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static SESSION_FILE: &str = ".adventofcode.session";
+
+/// Minimum time between two requests to the website, unless overridden with `AOC_THROTTLE_MS`.
+const DEFAULT_THROTTLE: Duration = Duration::from_secs(2);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    MissingYear,
+    /// Neither `AOC_USER_AGENT` nor `repository` in Cargo.toml says who is sending the requests.
+    MissingUserAgent,
+    /// The server answered with an error status, e.g. 404 for a puzzle that is not unlocked yet.
+    BadStatus(u16, String),
+    Transport(String),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or write it to ~/{SESSION_FILE}."
+            ),
+            AocClientError::MissingYear => write!(f, "AOC_YEAR is not set."),
+            AocClientError::MissingUserAgent => write!(
+                f,
+                "no contact for the User-Agent. Set AOC_USER_AGENT (e.g. to your repository or email) or `repository` in Cargo.toml."
+            ),
+            AocClientError::BadStatus(404, _) => {
+                write!(f, "the puzzle was not found, it may not be unlocked yet.")
+            }
+            AocClientError::BadStatus(400, _) => {
+                write!(
+                    f,
+                    "the request was rejected, the session cookie may have expired."
+                )
+            }
+            AocClientError::BadStatus(status, body) => {
+                write!(
+                    f,
+                    "the server answered with status {status}: {}",
+                    body.trim()
+                )
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::IoError(e) => write!(f, "could not access the file system: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+/// Response to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    /// The answer is wrong. Contains the hint the website gives, e.g. that the answer is too high.
+    Incorrect(Option<String>),
+    /// An answer was submitted too recently. Contains the time left to wait.
+    TooRecent(Option<String>),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A response the client does not know, as plain text.
+    Unknown(String),
+}
+
+pub struct Client {
+    base_url: String,
+    year: u16,
+    session: String,
+    throttle: Duration,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    #[must_use]
+    pub fn new(base_url: &str, year: u16, session: &str, user_agent: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            year,
+            session: session.trim().into(),
+            throttle: DEFAULT_THROTTLE,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(user_agent)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Builds a client from the environment, see the module docs.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let year = env::var("AOC_YEAR")
+            .ok()
+            .and_then(|x| x.parse().ok())
+            .ok_or(AocClientError::MissingYear)?;
+
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        let user_agent = user_agent().ok_or(AocClientError::MissingUserAgent)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        let mut client = Self::new(&base_url, year, &session, &user_agent);
+        if let Some(ms) = env::var("AOC_THROTTLE_MS")
+            .ok()
+            .and_then(|x| x.parse().ok())
+        {
+            client.throttle = Duration::from_millis(ms);
+        }
+        Ok(client)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_path(day)))
+    }
+
+    /// Fetches the puzzle page of a day as HTML.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&self.day_path(day))
+    }
+
+    /// Submits an answer to one part of a day.
+    pub fn submit(
+        &self,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitOutcome, AocClientError> {
+        let url = format!("{}{}/answer", self.base_url, self.day_path(day));
+        let level = part.to_string();
+
+        self.wait_for_throttle();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)]);
+
+        Ok(parse_outcome(&read_response(response)?))
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        let url = format!("{}{path}", self.base_url);
+
+        self.wait_for_throttle();
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+        read_response(response)
+    }
+
+    fn day_path(&self, day: Day) -> String {
+        format!("/{}/day/{}", self.year, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Sleeps until the throttle interval has passed since the last request of any process.
+    fn wait_for_throttle(&self) {
+        if self.throttle.is_zero() {
+            return;
+        }
+
+        let path = throttle_path();
+        let last = fs::read_to_string(&path)
+            .ok()
+            .and_then(|x| x.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+
+        if let Some(wait) = last.and_then(|last| (last + self.throttle).checked_sub(now())) {
+            thread::sleep(wait);
+        }
+
+        let _ = fs::write(path, now().as_millis().to_string());
+    }
+}
+
+/// A puzzle description written to `data/puzzles`.
+pub struct Puzzle {
+    pub markdown: String,
+    /// Whether the puzzle has more parts than when it was last downloaded. The previous version is kept,
+    /// see [`get_previous_puzzle_path`].
+    pub has_new_part: bool,
+}
+
+/// Downloads the input and puzzle description of a day to `data/inputs` and `data/puzzles`.
+/// Inputs never change, so an input that was already downloaded is not fetched again.
+pub fn download(day: Day) -> Result<Puzzle, AocClientError> {
+    let client = Client::from_env()?;
+    let input_path = get_input_path(day);
+    let puzzle_path = get_puzzle_path(day);
+
+    if fs::read_to_string(&input_path).is_ok_and(|input| !input.trim().is_empty()) {
+        println!(
+            "🎄 Input \"{}\" already exists, not downloading it again.",
+            &input_path
+        );
+    } else {
+        fs::write(&input_path, client.input(day)?)?;
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+
+    let puzzle = write_puzzle(day, markdown::from_html(&client.puzzle(day)?))?;
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(puzzle)
+}
+
+/// Fetches the puzzle description of a day and saves it to `data/puzzles`.
+pub fn read(day: Day) -> Result<Puzzle, AocClientError> {
+    let client = Client::from_env()?;
+    write_puzzle(day, markdown::from_html(&client.puzzle(day)?))
+}
+
+/// Where the version of a puzzle from before its last part was unlocked is kept.
+#[must_use]
+pub fn get_previous_puzzle_path(day: Day) -> String {
+    format!("data/puzzles/{day}.previous.md")
+}
+
+fn write_puzzle(day: Day, markdown: String) -> Result<Puzzle, AocClientError> {
+    let path = get_puzzle_path(day);
+    let previous = fs::read_to_string(&path)
+        .ok()
+        .filter(|previous| !previous.trim().is_empty());
+
+    let has_new_part = previous
+        .as_ref()
+        .is_some_and(|previous| markdown::parts(&markdown).len() > markdown::parts(previous).len());
+
+    if let (true, Some(previous)) = (has_new_part, &previous) {
+        fs::write(get_previous_puzzle_path(day), previous)?;
+    }
+
+    fs::write(&path, &markdown)?;
+    Ok(Puzzle {
+        markdown,
+        has_new_part,
+    })
+}
+
+/// Submits an answer to one part of a day and prints the response.
+pub fn submit(day: Day, part: u8, answer: &str) -> Result<SubmitOutcome, AocClientError> {
+    let outcome = Client::from_env()?.submit(day, part, answer)?;
+
+    match &outcome {
+        SubmitOutcome::Correct => println!("That's the right answer! ⭐"),
+        SubmitOutcome::Incorrect(hint) => match hint {
+            Some(hint) => println!("That's not the right answer, {hint}."),
+            None => println!("That's not the right answer."),
+        },
+        SubmitOutcome::TooRecent(wait) => match wait {
+            Some(wait) => println!("You gave an answer too recently. {wait}."),
+            None => println!("You gave an answer too recently."),
+        },
+        SubmitOutcome::WrongLevel => {
+            println!("This part is already solved or not unlocked yet.");
+        }
+        SubmitOutcome::Unknown(text) => println!("{text}"),
+    }
+
+    Ok(outcome)
+}
+
+fn get_input_path(day: Day) -> String {
+    format!("data/inputs/{day}.txt")
+}
+
+fn get_puzzle_path(day: Day) -> String {
+    format!("data/puzzles/{day}.md")
+}
+
+/// The User-Agent tells the website who is making the requests: `AOC_USER_AGENT`, or this crate's `repository`.
+fn user_agent() -> Option<String> {
+    if let Ok(user_agent) = env::var("AOC_USER_AGENT") {
+        return Some(user_agent).filter(|x| !x.trim().is_empty());
+    }
+
+    let repository = env!("CARGO_PKG_REPOSITORY");
+    (!repository.is_empty()).then(|| {
+        format!(
+            "advent_of_code/{} (+{repository})",
+            env!("CARGO_PKG_VERSION")
+        )
+    })
+}
+
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+
+    let path = match env::var("AOC_SESSION_FILE") {
+        Ok(path) => PathBuf::from(path),
+        Err(_) => PathBuf::from(env::var("HOME").ok()?).join(SESSION_FILE),
+    };
+
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn throttle_path() -> PathBuf {
+    env::temp_dir().join("advent_of_code-last-request")
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => Err(AocClientError::BadStatus(
+            status,
+            response.into_string().unwrap_or_default(),
+        )),
+        Err(ureq::Error::Transport(e)) => Err(AocClientError::Transport(e.to_string())),
+    }
+}
+
+/// The `<article>` elements of a page, which hold the puzzle description or the response to an answer.
+fn articles(html: &str) -> String {
+    let re = Regex::new(r"(?s)<article[^>]*>.*?</article>").unwrap();
+    re.find_iter(html)
+        .map(|m| m.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Strips the tags of an HTML snippet.
+fn to_text(html: &str) -> String {
+    let re = Regex::new(r"<[^>]*>").unwrap();
+    re.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+fn parse_outcome(html: &str) -> SubmitOutcome {
+    let text = to_text(&articles(html));
+    let capture = |pattern: &str| {
+        Regex::new(pattern)
+            .unwrap()
+            .captures(&text)
+            .map(|c| c[1].to_string())
+    };
+
+    if text.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if text.contains("That's not the right answer") {
+        SubmitOutcome::Incorrect(capture(r"(your answer is too (?:high|low))"))
+    } else if text.contains("You gave an answer too recently") {
+        SubmitOutcome::TooRecent(capture(r"(You have [^.]+ left to wait)"))
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::WrongLevel
+    } else {
+        SubmitOutcome::Unknown(text)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{articles, parse_outcome, AocClientError, Client, SubmitOutcome};
+    use crate::day;
+
+    /// Serves one request with `status` and `body` and returns the raw request.
+    fn stub(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn client(base_url: &str) -> Client {
+        let mut client = Client::new(
+            base_url,
+            2023,
+            "abc123\n",
+            "advent_of_code/test (+me@example.com)",
+        );
+        client.throttle = std::time::Duration::ZERO;
+        client
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (base_url, server) = stub(200, "1\n2\n3\n");
+        let input = client(&base_url).input(day!(5)).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "1\n2\n3\n");
+        assert!(request.starts_with("GET /2023/day/5/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request
+            .to_lowercase()
+            .contains("user-agent: advent_of_code/test (+me@example.com)\r\n"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = stub(
+            200,
+            "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+        );
+        let outcome = client(&base_url).submit(day!(12), 2, "4 2").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(outcome, SubmitOutcome::Correct);
+        assert!(request.starts_with("POST /2023/day/12/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=4+2"));
+    }
+
+    #[test]
+    fn reports_error_status() {
+        let (base_url, server) = stub(404, "Not Found");
+        let result = client(&format!("{base_url}/")).puzzle(day!(25));
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(AocClientError::BadStatus(404, _))));
+    }
+
+    #[test]
+    fn parses_submit_outcomes() {
+        let page = |text: &str| format!("<html><article><p>{text}</p></article></html>");
+
+        assert_eq!(
+            parse_outcome(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            SubmitOutcome::Incorrect(Some("your answer is too high".into()))
+        );
+        assert_eq!(
+            parse_outcome(&page(
+                "You gave an answer too recently. You have 42s left to wait."
+            )),
+            SubmitOutcome::TooRecent(Some("You have 42s left to wait".into()))
+        );
+        assert_eq!(
+            parse_outcome(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            SubmitOutcome::WrongLevel
+        );
+        assert_eq!(
+            parse_outcome(&page("Something <em>else</em>")),
+            SubmitOutcome::Unknown("Something else".into())
+        );
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = "<main><article class=\"day-desc\"><h2>Part 1</h2></article>\n<p>x</p><article class=\"day-desc\"><h2>Part 2</h2></article></main>";
+        assert_eq!(
+            articles(html),
+            "<article class=\"day-desc\"><h2>Part 1</h2></article>\n<article class=\"day-desc\"><h2>Part 2</h2></article>"
+        );
+    }
+}
@@ -0,0 +1,275 @@
+/// Module that persists benchmark results across runs and detects regressions against the last recorded run.
+/// Records are appended as tab-separated lines to `data/benchmarks.tsv`.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::readme_benchmarks::Timings;
+use crate::template::report::{self, Status};
+use crate::Day;
+
+static PATH: &str = "data/benchmarks.tsv";
+static HEADER: &str = "timestamp\tcommit\trustc\tday\tpart\tnanos";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// A single benchmarked part of a single run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub commit: String,
+    pub rustc: String,
+    pub day: Day,
+    /// One of `parse`, `1` or `2`.
+    pub part: String,
+    pub nanos: f64,
+}
+
+impl Record {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp, self.commit, self.rustc, self.day, self.part, self.nanos
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self, Error> {
+        let error = || Error::Parser(format!("Malformed benchmark record: {line}"));
+        let fields: Vec<&str> = line.split('\t').collect();
+
+        let [timestamp, commit, rustc, day, part, nanos] = fields[..] else {
+            return Err(error());
+        };
+
+        Ok(Self {
+            timestamp: timestamp.parse().map_err(|_| error())?,
+            commit: commit.into(),
+            rustc: rustc.into(),
+            day: day.parse().map_err(|_| error())?,
+            part: part.into(),
+            nanos: nanos.parse().map_err(|_| error())?,
+        })
+    }
+}
+
+/// A part that got slower than the configured threshold.
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub day: Day,
+    pub part: String,
+    pub previous_nanos: f64,
+    pub current_nanos: f64,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let part = report::label(&self.part);
+        let change = (self.current_nanos / self.previous_nanos - 1.0) * 100.0;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let (previous, current) = (
+            Duration::from_nanos(self.previous_nanos as u64),
+            Duration::from_nanos(self.current_nanos as u64),
+        );
+
+        write!(
+            f,
+            "Day {} {part}: {previous:.1?} → {current:.1?} (+{change:.1}%)",
+            self.day
+        )
+    }
+}
+
+/// Converts the timings of a run into history records, stamped with the current commit and toolchain.
+#[must_use]
+pub fn records_for(timings: &[Timings]) -> Vec<Record> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = command_output("git", &["rev-parse", "--short", "HEAD"]);
+    let rustc = command_output("rustc", &["--version"]);
+
+    timings
+        .iter()
+        .flat_map(|timing| {
+            [
+                ("parse", &timing.parse),
+                ("1", &timing.part_1),
+                ("2", &timing.part_2),
+            ]
+            .into_iter()
+            .filter_map(|(part, t)| {
+                t.as_ref()
+                    .filter(|t| t.status == Status::Solved)
+                    .map(|t| (timing.day, part, t.nanos))
+            })
+        })
+        .map(|(day, part, nanos)| Record {
+            timestamp,
+            commit: commit.clone(),
+            rustc: rustc.clone(),
+            day,
+            part: part.into(),
+            nanos,
+        })
+        .collect()
+}
+
+/// Compares the current records against the most recent previous record of the same day and part.
+/// Parts that got slower by more than `threshold` percent are returned as regressions.
+#[must_use]
+pub fn compare(history: &[Record], current: &[Record], threshold: f64) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|record| {
+            let previous = history
+                .iter()
+                .rev()
+                .find(|r| r.day == record.day && r.part == record.part)?;
+
+            if record.nanos > previous.nanos * (1.0 + threshold / 100.0) {
+                Some(Regression {
+                    day: record.day,
+                    part: record.part.clone(),
+                    previous_nanos: previous.nanos,
+                    current_nanos: record.nanos,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn load() -> Result<Vec<Record>, Error> {
+    if !Path::new(PATH).exists() {
+        return Ok(vec![]);
+    }
+
+    parse_content(&fs::read_to_string(PATH)?)
+}
+
+pub fn append(records: &[Record]) -> Result<(), Error> {
+    let is_new = !Path::new(PATH).exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(PATH)?;
+
+    if is_new {
+        writeln!(file, "{HEADER}")?;
+    }
+
+    for record in records {
+        writeln!(file, "{}", record.to_line())?;
+    }
+
+    Ok(())
+}
+
+fn parse_content(s: &str) -> Result<Vec<Record>, Error> {
+    s.lines()
+        .filter(|line| !line.is_empty() && *line != HEADER)
+        .map(Record::from_line)
+        .collect()
+}
+
+fn command_output(cmd: &str, args: &[&str]) -> String {
+    Command::new(cmd)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .trim()
+                .replace('\t', " ")
+        })
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, parse_content, Record, Regression, HEADER};
+    use crate::day;
+
+    fn record(timestamp: u64, part: &str, nanos: f64) -> Record {
+        Record {
+            timestamp,
+            commit: "abc1234".into(),
+            rustc: "rustc 1.74.0 (79e9716c9 2023-11-13)".into(),
+            day: day!(5),
+            part: part.into(),
+            nanos,
+        }
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let records = vec![record(1, "parse", 10.0), record(1, "1", 1250.5)];
+        let content = std::iter::once(HEADER.to_string())
+            .chain(records.iter().map(Record::to_line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(parse_content(&content).unwrap(), records);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_on_malformed_record() {
+        parse_content("1\tabc1234\trustc\t05\t1").unwrap();
+    }
+
+    #[test]
+    fn compares_against_last_run() {
+        let history = vec![
+            record(1, "1", 100.0),
+            record(1, "2", 100.0),
+            record(2, "1", 200.0),
+        ];
+        let current = vec![record(3, "1", 210.0), record(3, "2", 120.0)];
+        assert_eq!(
+            compare(&history, &current, 10.0),
+            vec![Regression {
+                day: day!(5),
+                part: "2".into(),
+                previous_nanos: 100.0,
+                current_nanos: 120.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn ignores_parts_without_history() {
+        let current = vec![record(1, "1", 100.0)];
+        assert!(compare(&[], &current, 10.0).is_empty());
+    }
+
+    #[test]
+    fn formats_regression() {
+        let regression = Regression {
+            day: day!(5),
+            part: "parse".into(),
+            previous_nanos: 1_000_000.0,
+            current_nanos: 1_250_000.0,
+        };
+        assert_eq!(
+            regression.to_string(),
+            "Day 05 Parse: 1.0ms → 1.2ms (+25.0%)"
+        );
+    }
+}
@@ -1,37 +1,164 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    io, mem, process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
 use crate::template::{
+    answers::Verdict,
+    benchmark_history,
     readme_benchmarks::{self, Timings},
+    registry::{self, Solution},
+    report::{PartReport, Status},
+    runner::BenchConfig,
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
 use crate::{all_days, Day};
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+#[derive(Clone, Copy)]
+pub struct Options {
+    pub is_release: bool,
+    pub is_timed: bool,
+    pub is_alloc: bool,
+    /// Compare timings against the last recorded run and fail on regressions.
+    pub compare: bool,
+    /// Slowdown in percent that counts as a regression.
+    pub threshold: f64,
+    /// Time limit for a whole day.
+    pub timeout: Option<Duration>,
+    /// Time limit for a single part.
+    pub part_timeout: Option<Duration>,
+    /// Run solutions from the registry inside this process instead of spawning a binary per day.
+    pub in_process: bool,
+    /// Number of days to run at the same time.
+    pub jobs: usize,
+    /// How timed runs are benched.
+    pub bench: BenchConfig,
+    /// Fail if an answer differs from the accepted one.
+    pub verify: bool,
+}
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
-        }
+pub fn handle(options: &Options, solutions: &[Solution]) {
+    let options = Options {
+        // comparing needs timings.
+        is_timed: options.is_timed || options.compare,
+        // in-process, solutions run with the profile of this binary.
+        is_release: options.is_release || (options.in_process && !cfg!(debug_assertions)),
+        ..*options
+    };
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    let Options {
+        is_release,
+        is_timed,
+        compare,
+        threshold,
+        in_process,
+        jobs,
+        verify,
+        ..
+    } = options;
+
+    // the history only holds release timings, debug timings would be flagged as regressions.
+    if compare && !is_release {
+        eprintln!("Comparing timings requires a release build. Try \"cargo time --compare\".");
+        process::exit(1);
+    }
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let jobs = if jobs > 1 && (is_timed || in_process) {
+        eprintln!("Ignoring --jobs: days run one at a time when timing or running in-process.");
+        1
+    } else {
+        jobs.max(1)
+    };
 
-        if output.is_empty() {
+    if in_process {
+        if !cfg!(feature = "registry") {
+            eprintln!(
+                "Running in-process requires the `registry` feature. Try \"cargo all-in-process\"."
+            );
+            process::exit(1);
+        }
+
+        if options.timeout.is_some() || options.part_timeout.is_some() {
+            eprintln!("Time limits are not supported when running in-process.");
+            process::exit(1);
+        }
+    } else if let Err(e) = child_commands::build_solutions(is_release) {
+        eprintln!("Failed to build solutions: {e:?}");
+        process::exit(1);
+    }
+
+    let mut timings: Vec<Timings> = vec![];
+    let mut timed_out: Vec<String> = vec![];
+    let mut panicked: Vec<String> = vec![];
+    let mut failed: Vec<String> = vec![];
+    let mut wrong_answers: Vec<String> = vec![];
+
+    let mut on_day = |day: Day, reports: Vec<PartReport>| {
+        timed_out.extend(part_labels(day, &reports, |r| r.status == Status::TimedOut));
+        panicked.extend(part_labels(day, &reports, |r| r.status == Status::Panicked));
+        failed.extend(part_labels(day, &reports, |r| r.status == Status::Failed));
+        wrong_answers.extend(part_labels(day, &reports, |r| {
+            r.verdict == Some(Verdict::Regressed)
+        }));
+
+        if reports.is_empty() {
             println!("Not solved.");
         } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+            timings.push(child_commands::to_timings(&reports, day));
         }
-    });
+    };
+
+    if jobs > 1 {
+        run_parallel(&options, jobs, |day, lines, reports| {
+            print_header(day);
+            lines.iter().for_each(child_commands::Line::print);
+            on_day(day, reports);
+        });
+    } else {
+        all_days().for_each(|day| {
+            print_header(day);
+
+            let reports = if in_process {
+                in_process::run_solution(day, solutions)
+            } else {
+                child_commands::run_solution(day, &options, &child_commands::Output::Forward)
+                    .unwrap()
+            };
+
+            on_day(day, reports);
+        });
+    }
+
+    print_parts("Timed out", &timed_out);
+    print_parts("Panicked", &panicked);
+    print_parts("Failed", &failed);
+    print_parts("Wrong answers", &wrong_answers);
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
+        let records = benchmark_history::records_for(&timings);
+
+        let regressions = if compare {
+            match benchmark_history::load() {
+                Ok(history) => benchmark_history::compare(&history, &records, threshold),
+                Err(e) => {
+                    eprintln!("Failed to read benchmark history: {e:?}");
+                    process::exit(1);
+                }
+            }
+        } else {
+            vec![]
+        };
+
         if is_release {
             match readme_benchmarks::update(timings, total_millis) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
@@ -39,13 +166,114 @@ pub fn handle(is_release: bool, is_timed: bool) {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
+
+            // a regressed run must not become the baseline the next `--compare` checks against.
+            if regressions.is_empty() {
+                match benchmark_history::append(&records) {
+                    Ok(()) => println!("Successfully appended benchmarks to history."),
+                    Err(_) => {
+                        eprintln!("Failed to append benchmarks to history.");
+                    }
+                }
+            } else {
+                println!("Not appending benchmarks to history, this run has regressions.");
+            }
+        }
+
+        if compare {
+            if regressions.is_empty() {
+                println!("No regressions above {threshold}% against the last recorded run.");
+            } else {
+                eprintln!("\n{ANSI_BOLD}Regressions above {threshold}%:{ANSI_RESET}");
+                for regression in &regressions {
+                    eprintln!("  ⚠ {regression}");
+                }
+                process::exit(1);
+            }
         }
     }
+
+    if verify && !wrong_answers.is_empty() {
+        process::exit(1);
+    }
+}
+
+/// Labels of the parts of a day that match `predicate`, e.g. `Day 05 Part 1`.
+fn part_labels(
+    day: Day,
+    reports: &[PartReport],
+    predicate: impl Fn(&PartReport) -> bool,
+) -> Vec<String> {
+    reports
+        .iter()
+        .filter(|r| predicate(r))
+        .map(|r| format!("Day {day} {}", r.label()))
+        .collect()
+}
+
+fn print_parts(title: &str, labels: &[String]) {
+    if !labels.is_empty() {
+        println!("\n{ANSI_BOLD}{title}:{ANSI_RESET} {}", labels.join(", "));
+    }
+}
+
+fn print_header(day: Day) {
+    if day > 1 {
+        println!();
+    }
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+/// Runs up to `jobs` days at the same time, buffering their output.
+/// `on_day` is called in day order as soon as a day and all days before it have finished.
+fn run_parallel(
+    options: &Options,
+    jobs: usize,
+    mut on_day: impl FnMut(Day, Vec<child_commands::Line>, Vec<PartReport>),
+) {
+    let days: Vec<Day> = all_days().collect();
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let (tx, days, next) = (tx.clone(), &days, &next);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(i) else {
+                    break;
+                };
+
+                let buffer = Arc::new(Mutex::new(vec![]));
+                let output = child_commands::Output::Buffer(Arc::clone(&buffer));
+                let reports = child_commands::run_solution(day, options, &output).unwrap();
+                let lines = mem::take(&mut *buffer.lock().unwrap());
+
+                tx.send((i, lines, reports)).unwrap();
+            });
+        }
+
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_print = 0;
+
+        for (i, lines, reports) in rx {
+            pending.insert(i, (lines, reports));
+            while let Some((lines, reports)) = pending.remove(&next_to_print) {
+                on_day(days[next_to_print], lines, reports);
+                next_to_print += 1;
+            }
+        }
+    });
 }
 
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    BuildFailed,
     Parser(String),
     IO(io::Error),
 }
@@ -61,42 +289,169 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
+/// With the `registry` feature, solutions are also compiled into the main binary.
+/// This module runs them directly, collecting their reports without any inter-process communication.
+mod in_process {
+    use super::{get_path_for_bin, registry, Solution};
+    use crate::template::report::{self, PartReport};
+    use crate::Day;
+    use std::{fs, path::Path};
+
+    pub fn run_solution(day: Day, solutions: &[Solution]) -> Vec<PartReport> {
+        if !Path::new(&get_path_for_bin(day)).exists() {
+            return vec![];
+        }
+
+        let Some(solution) = registry::find(solutions, day) else {
+            eprintln!("Day {day} is not part of this build, re-run to pick it up.");
+            return vec![];
+        };
+
+        let Ok(input) = fs::read_to_string(format!("data/inputs/{day}.txt")) else {
+            eprintln!("Could not read input file for day {day}.");
+            return vec![];
+        };
+
+        report::capture(|| (solution.run)(&input))
+    }
+}
+
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting their reports.
 mod child_commands {
-    use super::{get_path_for_bin, Error};
+    use super::{get_path_for_bin, Error, Options};
+    use crate::template::readme_benchmarks::{PartTiming, Timings};
+    use crate::template::report::{self, PartReport, Status};
+    use crate::template::runner::Summary;
+    use crate::template::watchdog;
     use crate::Day;
     use std::{
+        env, fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
+        sync::{Arc, Mutex},
         thread,
+        time::{Duration, Instant},
     };
 
+    /// Extra time a child gets beyond the day limit before it is killed from the outside.
+    /// Solutions enforce their limits themselves, this only catches children that fail to do so.
+    const TIMEOUT_GRACE: Duration = Duration::from_secs(2);
+
+    /// Builds all solution binaries once, so they can be executed directly afterwards.
+    pub fn build_solutions(is_release: bool) -> Result<(), Error> {
+        let mut args = vec!["build", "--quiet", "--bins"];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        let status = Command::new("cargo").args(&args).status()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::BuildFailed)
+        }
+    }
+
+    /// Path of the compiled solution binary, e.g. `target/release/05`.
+    /// The target directory is derived from the location of the running binary, which cargo puts there too.
+    fn get_path_for_exe(day: Day, is_release: bool) -> PathBuf {
+        let target_dir = env::var_os("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::current_exe()
+                    .ok()
+                    .and_then(|exe| Some(exe.parent()?.parent()?.to_path_buf()))
+            })
+            .unwrap_or_else(|| PathBuf::from("target"));
+
+        let profile = if is_release { "release" } else { "debug" };
+
+        target_dir
+            .join(profile)
+            .join(format!("{day}{}", env::consts::EXE_SUFFIX))
+    }
+
+    /// A line of output of a solution binary.
+    pub enum Line {
+        Stdout(String),
+        Stderr(String),
+    }
+
+    impl Line {
+        pub fn print(&self) {
+            match self {
+                Line::Stdout(line) => println!("{line}"),
+                Line::Stderr(line) => eprintln!("{line}"),
+            }
+        }
+    }
+
+    /// Where the output of a solution binary goes.
+    #[derive(Clone)]
+    pub enum Output {
+        /// Print output as it arrives.
+        Forward,
+        /// Collect output, so it can be printed later.
+        Buffer(Arc<Mutex<Vec<Line>>>),
+    }
+
+    impl Output {
+        fn write(&self, line: Line) {
+            match self {
+                Output::Forward => line.print(),
+                Output::Buffer(buffer) => buffer.lock().unwrap().push(line),
+            }
+        }
+    }
+
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        day: Day,
+        options: &Options,
+        output: &Output,
+    ) -> Result<Vec<PartReport>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let report_path = env::temp_dir().join(format!("aoc-{}-{day}.jsonl", process::id()));
+        let _ = fs::remove_file(&report_path);
+        let report_arg = report_path.to_string_lossy().to_string();
 
-        if is_release {
-            args.push("--release");
-        }
+        let mut args = vec!["--report", &report_arg];
 
-        if is_timed {
+        let bench_args = options.bench.to_args();
+        if options.is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
+            args.extend(bench_args.iter().map(String::as_str));
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        if options.is_alloc {
+            args.push("--alloc");
+        }
 
-        let mut cmd = Command::new("cargo")
+        let timeout = options.timeout.map(|t| t.as_secs_f64().to_string());
+        if let Some(timeout) = &timeout {
+            args.push("--timeout");
+            args.push(timeout);
+        }
+
+        let part_timeout = options.part_timeout.map(|t| t.as_secs_f64().to_string());
+        if let Some(part_timeout) = &part_timeout {
+            args.push("--part-timeout");
+            args.push(part_timeout);
+        }
+
+        // spawn child command with piped stdout/stderr and forward or buffer its output.
+        // results are collected from the report file the child writes to.
+
+        let mut cmd = Command::new(get_path_for_exe(day, options.is_release))
             .args(&args)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -105,85 +460,117 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
-        let thread = thread::spawn(move || {
+        let stderr_output = output.clone();
+        let stderr_thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+                stderr_output.write(Line::Stderr(line.unwrap()));
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let stdout_output = output.clone();
+        let stdout_thread = thread::spawn(move || {
+            stdout.lines().for_each(|line| {
+                stdout_output.write(Line::Stdout(line.unwrap()));
+            });
+        });
+
+        let deadline = options.timeout.map(|t| Instant::now() + t + TIMEOUT_GRACE);
+
+        let timed_out = loop {
+            if let Some(status) = cmd.try_wait()? {
+                // the watchdog in the child exits with its own code once a limit is exceeded.
+                break status.code() == Some(watchdog::EXIT_CODE);
+            }
+            if deadline.is_some_and(|d| Instant::now() > d) {
+                cmd.kill()?;
+                cmd.wait()?;
+                break true;
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
+
+        let mut reports = report::read(&report_path)?;
+        let _ = fs::remove_file(&report_path);
+
+        if timed_out {
+            mark_timed_out(&mut reports);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        Ok(reports)
+    }
 
-        Ok(output)
+    /// Reports all parts that did not finish before the child was stopped, by `all` or its watchdog, as timed out.
+    /// This includes parts that never started because an earlier part ran out of time.
+    fn mark_timed_out(reports: &mut Vec<PartReport>) {
+        for part in ["1", "2"] {
+            if !reports.iter().any(|r| r.part == part) {
+                reports.push(PartReport {
+                    part: part.into(),
+                    status: Status::TimedOut,
+                    answer: None,
+                    timing: (&Summary::single(Duration::ZERO)).into(),
+                    alloc: None,
+                    verdict: None,
+                    error: None,
+                });
+            }
+        }
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
+    /// Collects the timings of all solved, timed out, panicked and failed parts of a day.
+    pub fn to_timings(reports: &[PartReport], day: Day) -> Timings {
+        let mut timings = Timings {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
         };
 
-        output
+        reports
             .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
-
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+            .filter(|r| {
+                matches!(
+                    r.status,
+                    Status::Solved | Status::TimedOut | Status::Panicked | Status::Failed
+                )
             })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            .for_each(|r| {
+                let timing = to_part_timing(r);
+                if r.status == Status::Solved {
+                    timings.total_nanos += timing.nanos;
                 }
 
-                timings.total_nanos += nanos;
+                match r.part.as_str() {
+                    "parse" => timings.parse = Some(timing),
+                    "1" => timings.part_1 = Some(timing),
+                    "2" => timings.part_2 = Some(timing),
+                    _ => {}
+                }
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn to_part_timing(report: &PartReport) -> PartTiming {
+        let t = &report.timing;
+        let fmt = |nanos: u64| format!("{:.1?}", Duration::from_nanos(nanos));
+        let spread = |nanos: u64| (t.samples > 1).then(|| fmt(nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        PartTiming {
+            status: report.status,
+            median: fmt(t.median),
+            nanos: t.median as f64,
+            min: spread(t.min),
+            p95: spread(t.p95),
+            max: spread(t.max),
+            std_dev: spread(t.std_dev),
+            alloc: report.alloc.map(|a| a.to_string()),
+        }
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -202,47 +589,138 @@ mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{mark_timed_out, to_timings};
 
         use crate::day;
+        use crate::template::alloc::AllocStats;
+        use crate::template::report::{PartReport, Status, TimingReport};
+
+        fn report(part: &str, status: Status, median: u64, samples: u64) -> PartReport {
+            PartReport {
+                part: part.into(),
+                status,
+                answer: (status == Status::Solved).then(|| "42".into()),
+                timing: TimingReport {
+                    median,
+                    mean: median,
+                    min: median - 10,
+                    max: median + 20,
+                    std_dev: 5,
+                    p95: median + 15,
+                    samples,
+                    outliers: 0,
+                },
+                alloc: None,
+                verdict: None,
+                error: None,
+            }
+        }
 
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
+            let res = to_timings(
+                &[
+                    report("1", Status::Solved, 74, 100000),
+                    report("2", Status::Solved, 74_130_000, 99999),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap().median, "74.0ns");
+            assert_eq!(res.part_2.unwrap().median, "74.1ms");
+        }
+
+        #[test]
+        fn test_parse_step() {
+            let res = to_timings(
+                &[
+                    report("parse", Status::Solved, 1_000_000, 10),
+                    report("1", Status::Solved, 2_000_000, 10),
+                    report("2", Status::Solved, 3_000_000, 10),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 6000000_f64);
+            assert_eq!(res.parse.unwrap().median, "1.0ms");
+            assert_eq!(res.part_1.unwrap().median, "2.0ms");
+        }
+
+        #[test]
+        fn test_spread() {
+            let res = to_timings(
+                &[
+                    report("1", Status::Solved, 100, 1000),
+                    report("2", Status::Solved, 100, 1),
+                ],
+                day!(1),
+            );
+            let part_1 = res.part_1.unwrap();
+            assert_eq!(part_1.min.unwrap(), "90.0ns");
+            assert_eq!(part_1.p95.unwrap(), "115.0ns");
+            assert_eq!(part_1.max.unwrap(), "120.0ns");
+            assert_eq!(part_1.std_dev.unwrap(), "5.0ns");
+            assert_eq!(res.part_2.unwrap().min, None);
+        }
+
+        #[test]
+        fn test_alloc() {
+            let mut part_1 = report("1", Status::Solved, 100, 10);
+            part_1.alloc = Some(AllocStats {
+                allocations: 12,
+                bytes: 2048,
+                peak: 800,
+            });
+            let res = to_timings(&[part_1, report("2", Status::Solved, 100, 10)], day!(1));
+            assert_eq!(
+                res.part_1.unwrap().alloc.unwrap(),
+                "12 allocs, 2.0 KiB, peak 800 B"
+            );
+            assert_eq!(res.part_2.unwrap().alloc, None);
+        }
+
+        #[test]
+        fn test_timed_out() {
+            let res = to_timings(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    report("1", Status::Solved, 100, 1),
+                    report("2", Status::TimedOut, 5_000_000_000, 1),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 100_f64);
+            assert_eq!(res.part_2.unwrap().status, Status::TimedOut);
         }
 
         #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
+        fn test_panicked() {
+            let res = to_timings(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    report("parse", Status::Solved, 100, 1),
+                    report("1", Status::Panicked, 50, 1),
+                    report("2", Status::Solved, 200, 1),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos, 300_f64);
+            assert_eq!(res.part_1.unwrap().status, Status::Panicked);
+        }
+
+        #[test]
+        fn test_mark_timed_out() {
+            let mut reports = vec![report("parse", Status::Solved, 100, 1)];
+            mark_timed_out(&mut reports);
+            assert_eq!(reports.len(), 3);
+            assert_eq!(reports[1].part, "1");
+            assert_eq!(reports[1].status, Status::TimedOut);
+            assert_eq!(reports[2].part, "2");
         }
 
         #[test]
         fn test_missing_parts() {
-            let res = parse_exec_time(
+            let res = to_timings(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    report("1", Status::Unsolved, 100, 1),
+                    report("2", Status::Unsolved, 100, 1),
                 ],
                 day!(1),
             );
@@ -1,15 +1,41 @@
-use crate::template::aoc_cli;
+use crate::template::aoc_client;
+use crate::template::commands::{examples, sync_tests};
 use crate::Day;
+use std::io::{self, stdout, Write};
+use std::path::Path;
 use std::process;
 
 pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    match aoc_client::download(day) {
+        Ok(puzzle) if puzzle.has_new_part => on_new_part(day),
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("failed to download the puzzle: {e}");
+            process::exit(1);
+        }
     }
+}
+
+/// Points to the part that was unlocked since the last download and offers to update the examples and tests.
+pub fn on_new_part(day: Day) {
+    println!("---");
+    println!("🎄 A new part is unlocked! Type `cargo read {day} --new` to read it.");
+
+    if !confirm("Pull its example and answer into the tests?") {
+        return;
+    }
+
+    examples::extract(day, 2, false);
+    if Path::new(&format!("src/bin/{day}.rs")).exists() {
+        sync_tests::handle(day);
+    }
+}
+
+/// Asks a yes/no question on stdin, defaulting to no.
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    let _ = stdout().flush();
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).is_ok() && line.trim().eq_ignore_ascii_case("y")
 }
@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    io::{self, stdout, Write},
+    process,
+};
+
+use crate::template::markdown;
+use crate::Day;
+
+/// Lines of a block that are shown when picking between several blocks.
+const PREVIEW_LINES: usize = 6;
+
+/// Writes the example blocks of `data/puzzles/NN.md` to `data/examples`: the example of part one to `NN.txt`
+/// and, if part two brings its own, that one to `NN-2.txt`. When a part has several blocks, asks which one
+/// is the example.
+pub fn handle(day: Day, overwrite: bool) {
+    extract(day, 1, overwrite);
+}
+
+/// Like [`handle`], but only for the parts from `first_part` on, e.g. `2` once part two is unlocked.
+pub fn extract(day: Day, first_part: usize, overwrite: bool) {
+    let puzzle_path = format!("data/puzzles/{day}.md");
+    let example_path = format!("data/examples/{day}.txt");
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("Could not read \"{puzzle_path}\". Run `cargo download {day}` first.");
+        process::exit(1);
+    };
+
+    let mut first_example = fs::read_to_string(&example_path).ok();
+
+    for (index, part) in markdown::parts(&puzzle).into_iter().enumerate().take(2) {
+        let part_number = index + 1;
+        if part_number < first_part {
+            continue;
+        }
+
+        let blocks = markdown::code_blocks(part);
+
+        let Some(example) = pick(&blocks, part_number) else {
+            println!("No example picked for part {part_number}.");
+            continue;
+        };
+
+        if part_number == 1 {
+            write_example(&example_path, example, overwrite);
+            first_example = Some(example.clone());
+        } else if first_example.as_ref() == Some(example) {
+            println!("Part 2 uses the example of part 1.");
+        } else {
+            write_example(&format!("data/examples/{day}-2.txt"), example, overwrite);
+            println!("🎄 Run part 2 against it with `cargo solve {day} --example 2`.");
+        }
+    }
+}
+
+fn pick(blocks: &[String], part: usize) -> Option<&String> {
+    match blocks {
+        [] => None,
+        [block] => Some(block),
+        _ => {
+            println!("Part {part} has {} code blocks:", blocks.len());
+            for (i, block) in blocks.iter().enumerate() {
+                println!("\n[{}]\n{}", i + 1, preview(block));
+            }
+
+            loop {
+                print!(
+                    "\nWhich one is the example of part {part}? [1-{}, 0 to skip, default 1] ",
+                    blocks.len()
+                );
+                let _ = stdout().flush();
+
+                let mut line = String::new();
+                if io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+                    return blocks.first();
+                }
+
+                match parse_choice(&line, blocks.len()) {
+                    Some(choice) => return choice.map(|i| &blocks[i]),
+                    None => eprintln!("Expected a number between 0 and {}.", blocks.len()),
+                }
+            }
+        }
+    }
+}
+
+/// Parses the answer to the picker: `Some(None)` skips the part, `None` is an invalid answer.
+fn parse_choice(line: &str, count: usize) -> Option<Option<usize>> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Some(Some(0));
+    }
+
+    match line.parse::<usize>().ok()? {
+        0 => Some(None),
+        n if n <= count => Some(Some(n - 1)),
+        _ => None,
+    }
+}
+
+fn preview(block: &str) -> String {
+    let lines: Vec<&str> = block.lines().collect();
+    let mut preview = lines
+        .iter()
+        .take(PREVIEW_LINES)
+        .map(|line| format!("  {line}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if lines.len() > PREVIEW_LINES {
+        preview.push_str(&format!(
+            "\n  … ({} more lines)",
+            lines.len() - PREVIEW_LINES
+        ));
+    }
+    preview
+}
+
+fn write_example(path: &str, example: &str, overwrite: bool) {
+    let has_content = fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty());
+    if has_content && !overwrite {
+        eprintln!("\"{path}\" already has content, pass --overwrite to replace it.");
+        return;
+    }
+
+    match fs::write(path, example) {
+        Ok(()) => println!("Wrote example to \"{path}\"."),
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_choice, preview};
+
+    #[test]
+    fn parses_choices() {
+        assert_eq!(parse_choice("\n", 3), Some(Some(0)));
+        assert_eq!(parse_choice(" 3\n", 3), Some(Some(2)));
+        assert_eq!(parse_choice("0", 3), Some(None));
+        assert_eq!(parse_choice("4", 3), None);
+        assert_eq!(parse_choice("x", 3), None);
+    }
+
+    #[test]
+    fn previews_long_blocks() {
+        assert_eq!(preview("a\nb\n"), "  a\n  b");
+        assert_eq!(
+            preview("1\n2\n3\n4\n5\n6\n7\n8\n"),
+            "  1\n  2\n  3\n  4\n  5\n  6\n  … (2 more lines)"
+        );
+    }
+}
@@ -1,5 +1,7 @@
 pub mod all;
 pub mod download;
+pub mod examples;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod sync_tests;
@@ -1,16 +1,62 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::aoc_cli;
+use crate::template::commands::download;
+use crate::template::{aoc_client, markdown, terminal};
 use crate::Day;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+/// Shows the puzzle of a day from `data/puzzles`. It is only fetched from the website if it was not
+/// downloaded yet or `refresh` is set. With `new`, only the parts unlocked since the previous download are shown.
+pub fn handle(day: Day, refresh: bool, new: bool) {
+    let cached = fs::read_to_string(format!("data/puzzles/{day}.md")).ok();
+
+    let puzzle = match cached {
+        Some(puzzle) if !refresh => puzzle,
+        _ => match aoc_client::read(day) {
+            Ok(puzzle) => {
+                if puzzle.has_new_part {
+                    download::on_new_part(day);
+                }
+                puzzle.markdown
+            }
+            Err(e) => {
+                eprintln!("failed to read the puzzle: {e}");
+                process::exit(1);
+            }
+        },
+    };
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let puzzle = if new {
+        let Ok(previous) = fs::read_to_string(aoc_client::get_previous_puzzle_path(day)) else {
+            eprintln!("No previous version of the puzzle was kept, a new part is noticed when `cargo download {day}` runs again.");
+            process::exit(1);
+        };
+        new_parts(&puzzle, &previous)
+    } else {
+        puzzle
     };
+
+    terminal::page(&terminal::render(&puzzle, terminal::width()));
+}
+
+/// The parts of a puzzle that its previous version did not have.
+fn new_parts(puzzle: &str, previous: &str) -> String {
+    let known = markdown::parts(previous).len();
+    markdown::parts(puzzle)
+        .get(known..)
+        .unwrap_or_default()
+        .concat()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::new_parts;
+
+    #[test]
+    fn finds_new_parts() {
+        let previous = "## --- Day 1 ---\n\nOne.\n";
+        let puzzle = "## --- Day 1 ---\n\nOne.\n\n## --- Part Two ---\n\nTwo.\n";
+
+        assert_eq!(new_parts(puzzle, previous), "## --- Part Two ---\n\nTwo.\n");
+        assert_eq!(new_parts(previous, previous), "");
+    }
 }
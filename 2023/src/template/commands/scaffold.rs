@@ -1,12 +1,15 @@
 use std::{
     fs::{File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
+use crate::template::commands::sync_tests;
 use crate::Day;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// The module `cargo scaffold` writes, `cargo sync-tests` compares tests against it.
+pub(crate) const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
 
 pub fn part_one(_input: &str) -> Option<usize> {
     None
@@ -39,7 +42,11 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
 pub fn handle(day: Day) {
@@ -89,6 +96,10 @@ pub fn handle(day: Day) {
         }
     }
 
+    if Path::new(&format!("data/puzzles/{day}.md")).exists() {
+        sync_tests::handle(day);
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {}` to run your solution.", day);
 }
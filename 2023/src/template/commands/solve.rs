@@ -1,11 +1,50 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
 
+use crate::template::runner::BenchConfig;
 use crate::Day;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
+pub struct Options {
+    pub is_release: bool,
+    pub is_timed: bool,
+    pub bench: BenchConfig,
+    pub is_alloc: bool,
+    pub submit_part: Option<u8>,
+    /// File to read the input from instead of `data/inputs`, `-` for stdin.
+    pub input: Option<String>,
+    /// Read the input from `data/examples` instead of `data/inputs`.
+    pub example: bool,
+    /// Read the example of a part, e.g. `05-2.txt`, instead of `05.txt`.
+    pub example_part: Option<u8>,
+    /// Submit even though the input was overridden with `input`.
+    pub force_submit: bool,
+}
+
+pub fn handle(day: Day, options: &Options) {
+    let Options {
+        is_release: release,
+        is_timed: time,
+        bench,
+        is_alloc: alloc,
+        submit_part,
+        input,
+        example,
+        example_part,
+        force_submit,
+    } = options;
+
+    if let (false, Some(part)) = (example, example_part) {
+        eprintln!("Unexpected argument: {part}. Did you mean --example {part}?");
+        process::exit(1);
+    }
+
+    if *example && input.is_some() {
+        eprintln!("--example and --input can not be combined.");
+        process::exit(1);
+    }
+
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
-    if release {
+    if *release {
         cmd_args.push("--release".to_string());
     }
 
@@ -16,8 +55,30 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
-    if time {
+    if let Some(input) = input {
+        // `-` reads from stdin, which the child inherits.
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.to_string());
+    }
+
+    if *example {
+        cmd_args.push("--example".to_string());
+        if let Some(part) = example_part {
+            cmd_args.push(part.to_string());
+        }
+    }
+
+    if *force_submit {
+        cmd_args.push("--force-submit".to_string());
+    }
+
+    if *time {
         cmd_args.push("--time".to_string());
+        cmd_args.extend(bench.to_args());
+    }
+
+    if *alloc {
+        cmd_args.push("--alloc".to_string());
     }
 
     let mut cmd = Command::new("cargo")
@@ -0,0 +1,215 @@
+use std::{fs, path::Path, process};
+
+use crate::template::commands::scaffold::MODULE_TEMPLATE;
+use crate::template::markdown;
+use crate::Day;
+
+/// The assertion the scaffolded tests start with.
+const UNTOUCHED_ASSERT: &str = "assert_eq!(result, None);";
+const EXAMPLE_READ: &str = r#"read_file("examples", DAY)"#;
+const PART_TWO_EXAMPLE_READ: &str = r#"read_file_part("examples", DAY, 2)"#;
+const TESTS: [&str; 2] = ["fn test_part_one(", "fn test_part_two("];
+
+/// What happened to the test of a part.
+#[derive(Debug, PartialEq)]
+enum Sync {
+    Updated(String),
+    /// The test differs from the scaffolded one and is left alone.
+    Edited,
+    NoAnswer,
+    /// The answer is not a number, which the scaffolded `Option<usize>` can't hold.
+    NotANumber(String),
+    NoTest,
+}
+
+/// Sets the expected results of the scaffolded example tests in `src/bin/NN.rs` to the answers highlighted in
+/// `data/puzzles/NN.md`. If `data/examples/NN-2.txt` exists, the test of part two is pointed at it.
+pub fn handle(day: Day) {
+    let puzzle_path = format!("data/puzzles/{day}.md");
+    let module_path = format!("src/bin/{day}.rs");
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("Could not read \"{puzzle_path}\". Run `cargo download {day}` first.");
+        process::exit(1);
+    };
+
+    let Ok(source) = fs::read_to_string(&module_path) else {
+        eprintln!("Could not read \"{module_path}\". Run `cargo scaffold {day}` first.");
+        process::exit(1);
+    };
+
+    let answers: Vec<Option<String>> = markdown::parts(&puzzle)
+        .into_iter()
+        .map(markdown::highlighted_answer)
+        .collect();
+    let has_part_two_example = Path::new(&format!("data/examples/{day}-2.txt")).exists();
+
+    let (updated, results) = sync(&source, &answers, has_part_two_example);
+
+    for (part, result) in results.iter().enumerate() {
+        let name = ["part_one", "part_two"][part];
+        let part = part + 1;
+        match result {
+            Sync::Updated(value) => println!("Part {part}: expecting {value} for the example."),
+            Sync::Edited => {
+                println!("Part {part}: the test was changed since it was scaffolded, leaving it alone.")
+            }
+            Sync::NoAnswer => println!("Part {part}: no answer found in the puzzle."),
+            Sync::NotANumber(answer) => println!(
+                "Part {part}: the answer \"{answer}\" is not a number. Change the return type of `{name}` and set the expected result of its test yourself."
+            ),
+            Sync::NoTest => println!("Part {part}: no test found."),
+        }
+    }
+
+    if updated != source {
+        if let Err(e) = fs::write(&module_path, updated) {
+            eprintln!("Failed to write module file: {e}");
+            process::exit(1);
+        }
+        println!("Updated the tests in \"{module_path}\".");
+    }
+}
+
+/// Rewrites the assertions of `test_part_one` and `test_part_two` in `source` to expect `answers`, as long as
+/// the tests are still exactly as `cargo scaffold` wrote them.
+fn sync(
+    source: &str,
+    answers: &[Option<String>],
+    has_part_two_example: bool,
+) -> (String, [Sync; 2]) {
+    let template: Vec<&str> = MODULE_TEMPLATE.lines().collect();
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let mut results = [Sync::NoTest, Sync::NoTest];
+
+    for (part, test) in TESTS.into_iter().enumerate() {
+        let Some(range) = find_test(&lines, test) else {
+            continue;
+        };
+
+        let is_untouched = find_test(&template, test).is_some_and(|scaffolded| {
+            lines[range.clone()]
+                .iter()
+                .map(|line| line.trim())
+                .eq(template[scaffolded].iter().map(|line| line.trim()))
+        });
+
+        results[part] = match answers.get(part).cloned().flatten() {
+            _ if !is_untouched => Sync::Edited,
+            None => Sync::NoAnswer,
+            Some(answer) if answer.parse::<i128>().is_err() => Sync::NotANumber(answer),
+            Some(answer) => {
+                for line in &mut lines[range] {
+                    if line.trim() == UNTOUCHED_ASSERT {
+                        *line = line.replace("None", &format!("Some({answer})"));
+                    } else if part == 1 && has_part_two_example {
+                        *line = line.replace(EXAMPLE_READ, PART_TWO_EXAMPLE_READ);
+                    }
+                }
+                Sync::Updated(answer)
+            }
+        };
+    }
+
+    let mut updated = lines.join("\n");
+    if source.ends_with('\n') {
+        updated.push('\n');
+    }
+    (updated, results)
+}
+
+/// The lines of the test function whose signature contains `test`, up to its closing brace.
+fn find_test(lines: &[impl AsRef<str>], test: &str) -> Option<std::ops::Range<usize>> {
+    let start = lines.iter().position(|line| line.as_ref().contains(test))?;
+    let indent = lines[start].as_ref().len() - lines[start].as_ref().trim_start().len();
+
+    let end = lines[start..].iter().position(|line| {
+        let line = line.as_ref();
+        line.trim() == "}" && line.len() - line.trim_start().len() == indent
+    })?;
+    Some(start..start + end + 1)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{sync, Sync};
+    use crate::template::commands::scaffold::MODULE_TEMPLATE;
+
+    #[test]
+    fn fills_in_answers() {
+        let (updated, results) = sync(
+            MODULE_TEMPLATE,
+            &[Some("50".into()), Some("5905".into())],
+            false,
+        );
+
+        assert!(updated.contains("        assert_eq!(result, Some(50));\n"));
+        assert!(updated.contains("        assert_eq!(result, Some(5905));\n"));
+        assert!(!updated.contains("read_file_part"));
+        assert_eq!(
+            results,
+            [Sync::Updated("50".into()), Sync::Updated("5905".into())]
+        );
+        assert_eq!(updated.lines().count(), MODULE_TEMPLATE.lines().count());
+    }
+
+    #[test]
+    fn skips_answers_that_are_not_numbers() {
+        let (updated, results) = sync(MODULE_TEMPLATE, &[None, Some("AB".into())], false);
+
+        assert_eq!(updated, MODULE_TEMPLATE);
+        assert_eq!(results, [Sync::NoAnswer, Sync::NotANumber("AB".into())]);
+    }
+
+    #[test]
+    fn leaves_edited_tests_alone() {
+        let source = MODULE_TEMPLATE.replacen(
+            "assert_eq!(result, None);",
+            "assert_eq!(result, Some(7));",
+            1,
+        );
+        let (updated, results) = sync(&source, &[Some("50".into())], true);
+
+        assert_eq!(updated, source);
+        assert_eq!(results, [Sync::Edited, Sync::NoAnswer]);
+    }
+
+    #[test]
+    fn leaves_tests_with_edited_input_alone() {
+        let source = MODULE_TEMPLATE.replacen(
+            r#"read_file("examples", DAY)"#,
+            r#"read_file_part("examples", DAY, 3)"#,
+            1,
+        );
+        let (updated, results) = sync(&source, &[Some("50".into()), Some("7".into())], false);
+
+        assert!(updated.contains(
+            r#"read_file_part("examples", DAY, 3));
+        assert_eq!(result, None);"#
+        ));
+        assert_eq!(results, [Sync::Edited, Sync::Updated("7".into())]);
+    }
+
+    #[test]
+    fn points_part_two_at_its_example() {
+        let (updated, _) = sync(
+            MODULE_TEMPLATE,
+            &[Some("50".into()), Some("5905".into())],
+            true,
+        );
+
+        assert!(
+            updated.contains(r#"part_one(&advent_of_code::template::read_file("examples", DAY))"#)
+        );
+        assert!(updated.contains(
+            r#"part_two(&advent_of_code::template::read_file_part("examples", DAY, 2))"#
+        ));
+    }
+
+    #[test]
+    fn reports_missing_tests() {
+        let (updated, results) = sync("fn main() {}\n", &[Some("1".into())], false);
+        assert_eq!(updated, "fn main() {}\n");
+        assert_eq!(results, [Sync::NoTest, Sync::NoTest]);
+    }
+}
@@ -0,0 +1,608 @@
+/// Converts puzzle pages of the Advent of Code website to Markdown, as stored in `data/puzzles`.
+/// Only the puzzle descriptions (`<article class="day-desc">`) of a page are converted. Example blocks are kept
+/// verbatim, and answers emphasized in the text (`<code><em>`) are kept as ``*`answer`*``.
+use regex::Regex;
+
+/// Links on the website are relative to this.
+static SITE_URL: &str = "https://adventofcode.com";
+
+/// Elements that never have children.
+const VOID_ELEMENTS: &[&str] = &["br", "hr", "img", "input", "link", "meta", "wbr"];
+
+/// Elements that are skipped with everything inside them.
+const SKIPPED_ELEMENTS: &[&str] = &["head", "script", "style", "form", "nav"];
+
+#[derive(Debug, PartialEq)]
+enum Node {
+    Element {
+        name: String,
+        attributes: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+}
+
+impl Node {
+    fn element(name: &str, attributes: Vec<(String, String)>) -> Self {
+        Node::Element {
+            name: name.into(),
+            attributes,
+            children: vec![],
+        }
+    }
+
+    fn is(&self, element: &str) -> bool {
+        matches!(self, Node::Element { name, .. } if name == element)
+    }
+
+    fn push(&mut self, child: Node) {
+        if let Node::Element { children, .. } = self {
+            children.push(child);
+        }
+    }
+
+    fn attribute(&self, key: &str) -> Option<&str> {
+        match self {
+            Node::Element { attributes, .. } => attributes
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+}
+
+/// Converts a puzzle page, or a snippet of one, to Markdown.
+#[must_use]
+pub fn from_html(html: &str) -> String {
+    let nodes = parse(html);
+
+    let mut articles = vec![];
+    find_articles(&nodes, &mut articles);
+
+    let blocks = if articles.is_empty() {
+        blocks(&nodes)
+    } else {
+        articles.into_iter().flat_map(blocks).collect()
+    };
+
+    format!("{}\n", blocks.join("\n\n"))
+}
+
+/// Splits a puzzle description into its parts, each starting at a `##` heading.
+#[must_use]
+pub fn parts(markdown: &str) -> Vec<&str> {
+    let mut starts = vec![];
+    let mut offset = 0;
+    let mut fence = None;
+
+    for line in markdown.split_inclusive('\n') {
+        fence = next_fence(fence, line);
+        if fence.is_none() && line.starts_with("## ") {
+            starts.push(offset);
+        }
+        offset += line.len();
+    }
+
+    if starts.is_empty() {
+        return vec![markdown];
+    }
+
+    starts.push(markdown.len());
+    starts.windows(2).map(|w| &markdown[w[0]..w[1]]).collect()
+}
+
+/// The contents of the fenced code blocks of a description, e.g. its examples.
+#[must_use]
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut fence = None;
+
+    for line in markdown.lines() {
+        let was_open = fence.is_some();
+        fence = next_fence(fence, line);
+
+        match (was_open, fence.is_some()) {
+            (false, true) => blocks.push(String::new()),
+            (true, true) => {
+                let block = blocks.last_mut().unwrap();
+                block.push_str(line);
+                block.push('\n');
+            }
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+/// The last answer a part of a description highlights (``*`answer`*``), which is usually the answer to its
+/// example.
+#[must_use]
+pub fn highlighted_answer(part: &str) -> Option<String> {
+    let re = Regex::new(r"\*`([^`]+)`\*").unwrap();
+    let mut fence = None;
+
+    part.lines()
+        .filter(|line| {
+            let was_open = fence.is_some();
+            fence = next_fence(fence, line);
+            !was_open && fence.is_none()
+        })
+        .flat_map(|line| {
+            re.captures_iter(line)
+                .map(|c| c[1].to_string())
+                .collect::<Vec<_>>()
+        })
+        .last()
+}
+
+/// The fence of the code block that is open after `line`, given the fence of the one open before it.
+pub(crate) fn next_fence(fence: Option<usize>, line: &str) -> Option<usize> {
+    let line = line.trim_end();
+    let backticks = line.len() - line.trim_start_matches('`').len();
+
+    match fence {
+        None if backticks >= 3 => Some(backticks),
+        Some(open) if backticks >= open && backticks == line.len() => None,
+        _ => fence,
+    }
+}
+
+fn find_articles<'a>(nodes: &'a [Node], articles: &mut Vec<&'a [Node]>) {
+    for node in nodes {
+        if let Node::Element { name, children, .. } = node {
+            let is_description = node
+                .attribute("class")
+                .is_some_and(|class| class.split_whitespace().any(|c| c == "day-desc"));
+
+            if name == "article" && is_description {
+                articles.push(children);
+            } else {
+                find_articles(children, articles);
+            }
+        }
+    }
+}
+
+/// Parses HTML into a tree. Unclosed elements are closed with their parent, stray closing tags are ignored.
+fn parse(html: &str) -> Vec<Node> {
+    let attribute_re =
+        Regex::new(r#"([^\s=/>]+)(?:\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+)))?"#).unwrap();
+
+    // open elements, the document itself being the first.
+    let mut stack = vec![Node::element("", vec![])];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            let name = tag[..end].trim().to_lowercase();
+            rest = tag.get(end + 1..).unwrap_or("");
+
+            if let Some(index) = stack.iter().rposition(|node| node.is(&name)) {
+                while stack.len() > index {
+                    close(&mut stack);
+                }
+            }
+        } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            let end = tag_end(rest);
+            let tag = rest[1..end].trim_end_matches('/');
+            let self_closing = rest[..end].ends_with('/');
+            rest = rest.get(end + 1..).unwrap_or("");
+
+            let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+            let name = tag[..name_end].to_lowercase();
+            let attributes = attribute_re
+                .captures_iter(&tag[name_end..])
+                .map(|c| {
+                    let value = c.get(2).or(c.get(3)).or(c.get(4));
+                    (
+                        c[1].to_lowercase(),
+                        decode_entities(value.map_or("", |v| v.as_str())),
+                    )
+                })
+                .collect();
+
+            if name == "script" || name == "style" {
+                let closing = format!("</{name}");
+                rest = rest.find(&closing).map_or("", |end| &rest[end..]);
+            }
+
+            // a list item or paragraph ends where the next one starts.
+            if (name == "li" || name == "p") && stack.last().is_some_and(|node| node.is(&name)) {
+                close(&mut stack);
+            }
+
+            stack.push(Node::element(&name, attributes));
+            if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                close(&mut stack);
+            }
+        } else {
+            // the first character is part of the text even if it is a `<` that does not start a tag.
+            let first = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[first..]
+                .find('<')
+                .map_or(rest.len(), |end| end + first);
+            let text = decode_entities(&rest[..end]);
+            stack.last_mut().unwrap().push(Node::Text(text));
+            rest = &rest[end..];
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    match stack.pop() {
+        Some(Node::Element { children, .. }) => children,
+        _ => vec![],
+    }
+}
+
+/// Closes the innermost open element and adds it to its parent.
+fn close(stack: &mut Vec<Node>) {
+    let node = stack.pop().unwrap();
+    stack.last_mut().unwrap().push(node);
+}
+
+/// Index of the `>` that ends the tag `s` starts with, skipping quoted attribute values.
+fn tag_end(s: &str) -> usize {
+    let mut quote = None;
+
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return i,
+            _ => {}
+        }
+    }
+
+    s.len()
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..=end])?, end + 2)));
+
+        match entity {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Renders nodes as Markdown blocks. Inline content between blocks is rendered as a paragraph.
+fn blocks(nodes: &[Node]) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut paragraph = String::new();
+
+    let flush = |paragraph: &mut String, blocks: &mut Vec<String>| {
+        let text = paragraph.trim();
+        if !text.is_empty() {
+            blocks.push(text.to_string());
+        }
+        paragraph.clear();
+    };
+
+    for node in nodes {
+        let Node::Element { name, children, .. } = node else {
+            paragraph.push_str(&inline(node));
+            continue;
+        };
+
+        match name.as_str() {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                flush(&mut paragraph, &mut blocks);
+                let level = usize::from(name.as_bytes()[1] - b'0');
+                blocks.push(format!(
+                    "{} {}",
+                    "#".repeat(level),
+                    inline_all(children).trim()
+                ));
+            }
+            "p" => {
+                flush(&mut paragraph, &mut blocks);
+                paragraph.push_str(&inline_all(children));
+                flush(&mut paragraph, &mut blocks);
+            }
+            "pre" => {
+                flush(&mut paragraph, &mut blocks);
+                blocks.push(code_block(&text(children)));
+            }
+            "ul" | "ol" => {
+                flush(&mut paragraph, &mut blocks);
+                blocks.push(list(children, name == "ol"));
+            }
+            "html" | "body" | "main" | "article" | "div" | "section" | "header" | "footer" => {
+                flush(&mut paragraph, &mut blocks);
+                blocks.extend(self::blocks(children));
+            }
+            _ => paragraph.push_str(&inline(node)),
+        }
+    }
+
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn inline_all(nodes: &[Node]) -> String {
+    nodes.iter().map(inline).collect()
+}
+
+fn inline(node: &Node) -> String {
+    let (name, children) = match node {
+        Node::Text(text) => return escape(&collapse_whitespace(text)),
+        Node::Element { name, children, .. } => (name.as_str(), children),
+    };
+
+    match name {
+        "em" | "i" => emphasize(&inline_all(children), "*"),
+        "strong" | "b" => emphasize(&inline_all(children), "**"),
+        "code" => match &children[..] {
+            [Node::Element { name, children, .. }] if name == "em" => {
+                format!("*{}*", code_span(&text(children)))
+            }
+            _ => code_span(&text(children)),
+        },
+        "a" => match node.attribute("href") {
+            Some(href) => format!("[{}]({})", inline_all(children).trim(), resolve(href)),
+            None => inline_all(children),
+        },
+        "br" => "  \n".into(),
+        _ if SKIPPED_ELEMENTS.contains(&name) => String::new(),
+        _ => inline_all(children),
+    }
+}
+
+/// Wraps text in `marker`, keeping surrounding whitespace outside of it so the emphasis stays valid Markdown.
+fn emphasize(text: &str, marker: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.into();
+    }
+
+    let start = text.len() - text.trim_start().len();
+    let end = text.trim_end().len();
+    format!(
+        "{}{marker}{trimmed}{marker}{}",
+        &text[..start],
+        &text[end..]
+    )
+}
+
+fn code_span(text: &str) -> String {
+    let text = collapse_whitespace(text);
+    if text.contains('`') {
+        format!("`` {text} ``")
+    } else {
+        format!("`{text}`")
+    }
+}
+
+fn code_block(text: &str) -> String {
+    // a newline right after `<pre>` is not part of the content.
+    let text = text.strip_prefix('\n').unwrap_or(text);
+    let newline = if text.ends_with('\n') { "" } else { "\n" };
+    let fence = if text.contains("```") { "````" } else { "```" };
+    format!("{fence}\n{text}{newline}{fence}")
+}
+
+fn list(items: &[Node], ordered: bool) -> String {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            Node::Element { name, children, .. } if name == "li" => Some(children),
+            _ => None,
+        })
+        .enumerate()
+        .map(|(i, children)| {
+            let marker = if ordered {
+                format!("{}. ", i + 1)
+            } else {
+                "- ".into()
+            };
+            let indent = " ".repeat(marker.len());
+
+            let body = blocks(children).join("\n");
+            let body = body
+                .lines()
+                .enumerate()
+                .map(|(j, line)| match (j, line.is_empty()) {
+                    (0, _) | (_, true) => line.to_string(),
+                    _ => format!("{indent}{line}"),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            format!("{marker}{body}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The text of nodes as it is, without any markup.
+fn text(nodes: &[Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => text.clone(),
+            Node::Element { children, .. } => text(children),
+        })
+        .collect()
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    let mut collapsed = String::with_capacity(s.len());
+    let mut was_whitespace = false;
+
+    for c in s.chars() {
+        if c.is_whitespace() {
+            if !was_whitespace {
+                collapsed.push(' ');
+            }
+            was_whitespace = true;
+        } else {
+            collapsed.push(c);
+            was_whitespace = false;
+        }
+    }
+
+    collapsed
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('*', "\\*")
+        .replace('`', "\\`")
+}
+
+fn resolve(href: &str) -> String {
+    if href.starts_with('/') {
+        format!("{SITE_URL}{href}")
+    } else {
+        href.into()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, from_html, highlighted_answer, parts};
+
+    #[test]
+    fn converts_puzzle_pages() {
+        let html = include_str!("../../data/fixtures/puzzle.html");
+        let markdown = include_str!("../../data/fixtures/puzzle.md");
+        assert_eq!(from_html(html), markdown);
+    }
+
+    #[test]
+    fn keeps_non_ascii_text_after_tags() {
+        assert_eq!(from_html("<p>—dash</p>"), "—dash\n");
+        assert_eq!(
+            from_html("<p><em>×</em>→<code>é</code></p><pre><code>└─┘\n</code></pre>"),
+            "*×*→`é`\n\n```\n└─┘\n```\n"
+        );
+    }
+
+    #[test]
+    fn converts_snippets_without_articles() {
+        assert_eq!(
+            from_html("<p>Hello <em>world</em>!</p><p>x &lt; y</p>"),
+            "Hello *world*!\n\nx < y\n"
+        );
+    }
+
+    #[test]
+    fn keeps_example_blocks_verbatim() {
+        assert_eq!(
+            from_html("<pre><code>  *.*\n <em>#</em>  &amp;\n\n</code></pre>"),
+            "```\n  *.*\n #  &\n\n```\n"
+        );
+    }
+
+    #[test]
+    fn keeps_emphasized_answers() {
+        assert_eq!(
+            from_html(
+                "<p>The answer is <code><em>4 2</em></code>, not <em><code>1</code></em>.</p>"
+            ),
+            "The answer is *`4 2`*, not *`1`*.\n"
+        );
+    }
+
+    #[test]
+    fn escapes_markup_in_text() {
+        assert_eq!(
+            from_html("<p>2*3 is <code>2*3</code>, `x` is <code>a`b</code></p>"),
+            "2\\*3 is `2*3`, \\`x\\` is `` a`b ``\n"
+        );
+    }
+
+    #[test]
+    fn tolerates_unclosed_elements() {
+        assert_eq!(
+            from_html("<ul><li>one<li>two</ul><p>three</b>"),
+            "- one\n- two\n\nthree\n"
+        );
+    }
+
+    #[test]
+    fn splits_parts() {
+        let markdown = include_str!("../../data/fixtures/puzzle.md");
+        let parts = parts(markdown);
+
+        assert_eq!(parts.len(), 2);
+        assert!(parts[0].starts_with("## --- Day 7: Lantern Sorting ---\n"));
+        assert!(parts[1].starts_with("## --- Part Two ---\n"));
+        assert_eq!(parts.concat(), markdown);
+        assert_eq!(super::parts("no headings"), vec!["no headings"]);
+    }
+
+    #[test]
+    fn finds_code_blocks() {
+        let markdown = include_str!("../../data/fixtures/puzzle.md");
+        let example = "3 red\n11 blue\n7 <unknown>\n";
+        assert_eq!(code_blocks(markdown), vec![example, example]);
+
+        assert_eq!(
+            code_blocks("````\n```\n## x\n````\ntext\n```\n\n```"),
+            vec!["```\n## x\n", "\n"]
+        );
+        assert_eq!(parts("````\n## x\n````\n").len(), 1);
+    }
+
+    #[test]
+    fn finds_highlighted_answers() {
+        let parts = parts(include_str!("../../data/fixtures/puzzle.md"));
+        assert_eq!(highlighted_answer(parts[0]), Some("50".into()));
+        assert_eq!(highlighted_answer(parts[1]), Some("5905".into()));
+
+        assert_eq!(
+            highlighted_answer("*`1`* and *`2`*\n```\n*`3`*\n```\n"),
+            Some("2".into())
+        );
+        assert_eq!(highlighted_answer("`4` and *5*"), None);
+    }
+}
@@ -1,10 +1,23 @@
 use crate::Day;
+use std::io::{self, Read};
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod commands;
+pub mod markdown;
+pub mod ocr;
+pub mod panics;
+pub mod progress;
 pub mod readme_benchmarks;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod terminal;
+pub mod watchdog;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -31,18 +44,141 @@ pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
     f.expect("could not open input file")
 }
 
+/// Reads the puzzle input for a day from `data/inputs`. With `--input <path>` the input is read from that
+/// file instead, with `--input -` it is read from stdin. With `--example` the example from `data/examples`
+/// is read, with `--example <part>` the part's example, e.g. `01-2.txt`.
+#[must_use]
+pub fn read_input(day: Day) -> String {
+    let args: Vec<String> = env::args().collect();
+    let value = |flag: &str| {
+        args.iter()
+            .position(|x| x == flag)
+            .map(|i| args.get(i + 1).map(String::as_str))
+    };
+
+    if let Some(part) = value("--example") {
+        return match part.and_then(|x| x.parse::<u8>().ok()) {
+            Some(part) => read_file_part("examples", day, part),
+            None => read_file("examples", day),
+        };
+    }
+
+    match value("--input").flatten() {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .expect("could not read input from stdin");
+            input
+        }
+        Some(path) => fs::read_to_string(path).expect("could not open input file"),
+        None => read_file("inputs", day),
+    }
+}
+
+/// Helper function that reads the example for a part: `01-2.txt` if it exists, `01.txt` otherwise.
+#[must_use]
+pub fn read_example(day: Day, part: u8) -> String {
+    let cwd = env::current_dir().unwrap();
+    let part_path = cwd
+        .join("data")
+        .join("examples")
+        .join(format!("{day}-{part}.txt"));
+    if part_path.exists() {
+        read_file_part("examples", day, part)
+    } else {
+        read_file("examples", day)
+    }
+}
+
 /// Creates the constant `DAY` and sets up the input and runner for each part.
+/// The input is read with [`read_input`], so `--input` and `--example` are honored.
+///
+/// An optional `parse` function can be passed as the second argument. Its output is timed as a separate
+/// step and a reference to it is handed to both `part_one` and `part_two` in place of the raw input.
+///
+/// Alternative implementations of a part can be compared with a trailing `variants` block, e.g.
+/// `solution!(5, parse, variants { 2: [part_two_naive, part_two_fast] })`. After the regular parts, each
+/// variant is run and timed on the same input, its speedup over the first variant is printed, and
+/// differing answers cause a panic. A `variants_agree_on_example` test checks the same on the example input.
+///
+/// Besides `main`, this defines a hidden `__run` function that solves both parts for a given input.
+/// The main binary calls it directly when built with the `registry` feature, see `build.rs`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($day:expr $(, variants { $($vpart:literal: [$($variant:ident),+ $(,)?]),* $(,)? })?) => {
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
+        #[doc(hidden)]
+        pub fn __run(input: &str) {
+            use advent_of_code::template::runner::*;
+            advent_of_code::template::answers::set_input(input);
+            run_part(part_one, input, DAY, 1);
+            run_part(part_two, input, DAY, 2);
+            $($(
+                run_variants(&[$((stringify!($variant), $variant as fn(_) -> _)),+], input, $vpart);
+            )*)?
+        }
+
         fn main() {
+            let input = advent_of_code::template::read_input(DAY);
+            __run(&input);
+        }
+
+        $(
+            #[cfg(test)]
+            #[test]
+            fn variants_agree_on_example() {
+                use advent_of_code::template::runner::*;
+                $(
+                    let input = advent_of_code::template::read_example(DAY, $vpart);
+                    assert_variants_agree(
+                        &[$((stringify!($variant), $variant as fn(_) -> _)),+],
+                        input.as_str(),
+                        $vpart,
+                    );
+                )*
+            }
+        )?
+    };
+    ($day:expr, $parse:ident $(, variants { $($vpart:literal: [$($variant:ident),+ $(,)?]),* $(,)? })?) => {
+        /// The current day.
+        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+
+        #[doc(hidden)]
+        pub fn __run(input: &str) {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            advent_of_code::template::answers::set_input(input);
+            let Some(parsed) = run_parse($parse, input) else {
+                return;
+            };
+            run_part(|parsed| part_one(parsed), &parsed, DAY, 1);
+            run_part(|parsed| part_two(parsed), &parsed, DAY, 2);
+            $($(
+                run_variants(&[$((stringify!($variant), |parsed| $variant(parsed))),+], &parsed, $vpart);
+            )*)?
+        }
+
+        fn main() {
+            let input = advent_of_code::template::read_input(DAY);
+            __run(&input);
         }
+
+        $(
+            #[cfg(test)]
+            #[test]
+            fn variants_agree_on_example() {
+                use advent_of_code::template::runner::*;
+                $(
+                    let parsed = $parse(&advent_of_code::template::read_example(DAY, $vpart));
+                    assert_variants_agree(
+                        &[$((stringify!($variant), |parsed| $variant(parsed))),+],
+                        &parsed,
+                        $vpart,
+                    );
+                )*
+            }
+        )?
     };
 }
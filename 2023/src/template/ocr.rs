@@ -0,0 +1,191 @@
+//! Reads the block letters some puzzles draw their answer with, e.g. the output of 2022 day 10.
+//! Two fonts are known: letters that are 6 pixels tall (usually 4 wide), and letters that are 10 pixels tall
+//! and 6 wide. Letters are matched column by column, so any spacing or offset between them is fine.
+
+/// Letters of the font that is 6 pixels tall.
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// Letters of the font that is 10 pixels tall.
+#[rustfmt::skip]
+const LARGE: &[(char, &str)] = &[
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+/// Reads the letters drawn by `rows`, `true` being a lit pixel.
+/// Returns `None` if the drawing is not made up of known letters only.
+#[must_use]
+pub fn recognize(rows: &[Vec<bool>]) -> Option<String> {
+    let lit_rows: Vec<usize> = (0..rows.len())
+        .filter(|&y| rows[y].iter().any(|&lit| lit))
+        .collect();
+    let (&top, &bottom) = (lit_rows.first()?, lit_rows.last()?);
+    let rows = &rows[top..=bottom];
+
+    let font = match rows.len() {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let columns: Vec<Vec<bool>> = (0..width)
+        .map(|x| {
+            rows.iter()
+                .map(|row| row.get(x).copied().unwrap_or(false))
+                .collect()
+        })
+        .collect();
+
+    let glyphs: Vec<(char, Vec<Vec<bool>>)> =
+        font.iter().map(|&(c, art)| (c, glyph(art))).collect();
+
+    let mut letters = String::new();
+    let mut x = 0;
+
+    while x < columns.len() {
+        if columns[x].iter().all(|&lit| !lit) {
+            x += 1;
+            continue;
+        }
+
+        // the longest match wins, so a letter is never read as a narrower letter it starts with.
+        let (c, glyph) = glyphs
+            .iter()
+            .filter(|(_, glyph)| columns[x..].starts_with(glyph))
+            .max_by_key(|(_, glyph)| glyph.len())?;
+
+        letters.push(*c);
+        x += glyph.len();
+    }
+
+    Some(letters).filter(|letters| !letters.is_empty())
+}
+
+/// Reads the letters drawn by `s`, with `#` or `█` as lit pixels.
+#[must_use]
+pub fn recognize_str(s: &str) -> Option<String> {
+    recognize(&to_rows(s))
+}
+
+fn to_rows(s: &str) -> Vec<Vec<bool>> {
+    s.lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect()
+}
+
+/// Columns of a letter, trimmed to its lit pixels.
+fn glyph(art: &str) -> Vec<Vec<bool>> {
+    let rows = to_rows(art);
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    let columns: Vec<Vec<bool>> = (0..width)
+        .map(|x| rows.iter().map(|row| row[x]).collect())
+        .collect();
+
+    let is_lit = |column: &&Vec<bool>| column.iter().any(|&lit| lit);
+    let start = columns.iter().position(|c| is_lit(&c)).unwrap_or(0);
+    let end = columns
+        .iter()
+        .rposition(|c| is_lit(&c))
+        .map_or(0, |x| x + 1);
+    columns[start..end].to_vec()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{recognize, recognize_str, LARGE, SMALL};
+
+    /// Draws `letters` in a font, with `spacing` blank columns between letters.
+    fn draw(font: &[(char, &str)], letters: &str, spacing: usize) -> String {
+        let height = font[0].1.lines().count();
+        let mut lines = vec![String::new(); height];
+
+        for c in letters.chars() {
+            let art = font.iter().find(|(l, _)| *l == c).unwrap().1;
+            for (line, row) in lines.iter_mut().zip(art.lines()) {
+                line.push_str(row);
+                line.push_str(&".".repeat(spacing));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    #[test]
+    fn recognizes_small_letters() {
+        let all: String = SMALL.iter().map(|(c, _)| c).collect();
+        assert_eq!(recognize_str(&draw(SMALL, &all, 1)), Some(all));
+        assert_eq!(recognize_str(&draw(SMALL, "EHZ", 1)), Some("EHZ".into()));
+    }
+
+    #[test]
+    fn recognizes_large_letters() {
+        let all: String = LARGE.iter().map(|(c, _)| c).collect();
+        assert_eq!(recognize_str(&draw(LARGE, &all, 2)), Some(all));
+    }
+
+    #[test]
+    fn recognizes_touching_letters() {
+        // `Y` is one pixel wider than the other letters and touches the next one.
+        assert_eq!(recognize_str(&draw(SMALL, "YL", 0)), Some("YL".into()));
+    }
+
+    #[test]
+    fn recognizes_pixels_with_offset() {
+        let art = format!("\n\n{}\n", draw(SMALL, "IRL", 1))
+            .lines()
+            .map(|line| format!("...{}", line.replace('#', "█")))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(recognize_str(&art), Some("IRL".into()));
+
+        let rows: Vec<Vec<bool>> = draw(SMALL, "OK", 1)
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(recognize(&rows), Some("OK".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_drawings() {
+        assert_eq!(recognize_str("#.#\n.#.\n#.#"), None);
+        assert_eq!(
+            recognize_str(&draw(SMALL, "A", 1).replace("####", "#.##")),
+            None
+        );
+        assert_eq!(recognize_str("....\n...."), None);
+    }
+}
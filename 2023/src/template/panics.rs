@@ -0,0 +1,85 @@
+/// Catches panics of solution code, so that a failing part does not take down the rest of the run.
+/// While [`catch`] runs, the default panic message is suppressed and the message and location are
+/// captured instead, so the runner can print them in its own format.
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// A panic caught by [`catch`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Source location of the panic, e.g. `src/bin/05.rs:42:10`.
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} at {location}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Runs `func` and returns the panic it raised, if any.
+pub fn catch<T>(func: impl FnOnce() -> T) -> Result<T, Panic> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                let location = info.location().map(ToString::to_string);
+                LOCATION.set(location);
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING.set(was_catching);
+
+    result.map_err(|payload| Panic {
+        message: message(payload.as_ref()),
+        location: LOCATION.take(),
+    })
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).into()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "panicked".into()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::catch;
+
+    #[test]
+    fn catches_panics() {
+        let panic = catch(|| -> u32 { panic!("no solution for {}", 42) }).unwrap_err();
+        assert_eq!(panic.message, "no solution for 42");
+        assert!(panic.location.unwrap().contains("panics.rs"));
+    }
+
+    #[test]
+    fn passes_through_results() {
+        assert_eq!(catch(|| 42), Ok(42));
+    }
+}
@@ -0,0 +1,148 @@
+/// Progress reporting for long-running solutions.
+/// The status line is rendered on stderr and cleared before the runner prints a result, so it never mixes
+/// into the answer line. Rendering is skipped while benching and when stderr is not a terminal.
+///
+/// ```no_run
+/// # use advent_of_code::template::progress::Progress;
+/// let progress = Progress::new(1_000_000);
+/// progress.message("searching");
+/// for _ in 0..1_000_000 {
+///     progress.tick();
+/// }
+/// ```
+use std::{
+    io::{stderr, IsTerminal, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::template::{ANSI_ITALIC, ANSI_RESET};
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+static RENDERED: AtomicBool = AtomicBool::new(false);
+
+/// Minimum time between two renders of the status line.
+const RENDER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Only check the clock every `TICK_BATCH` ticks, to keep `tick` cheap in hot loops.
+const TICK_BATCH: u64 = 1024;
+
+pub struct Progress {
+    total: u64,
+    current: AtomicU64,
+    message: Mutex<String>,
+    last_render: Mutex<Option<Instant>>,
+}
+
+impl Progress {
+    /// Creates a progress tracker expecting `total` ticks.
+    #[must_use]
+    pub fn new(total: u64) -> Self {
+        Self {
+            total,
+            current: AtomicU64::new(0),
+            message: Mutex::new(String::new()),
+            last_render: Mutex::new(None),
+        }
+    }
+
+    /// Advances progress by one.
+    pub fn tick(&self) {
+        self.inc(1);
+    }
+
+    /// Advances progress by `n`.
+    pub fn inc(&self, n: u64) {
+        let previous = self.current.fetch_add(n, Ordering::Relaxed);
+        if previous / TICK_BATCH != (previous + n) / TICK_BATCH {
+            self.render(false);
+        }
+    }
+
+    /// Sets the message displayed next to the progress and re-renders the status line.
+    pub fn message(&self, message: impl Into<String>) {
+        *self.message.lock().unwrap() = message.into();
+        self.render(true);
+    }
+
+    fn render(&self, force: bool) {
+        if !is_active() {
+            return;
+        }
+
+        let mut last_render = self.last_render.lock().unwrap();
+        if !force && last_render.is_some_and(|t| t.elapsed() < RENDER_INTERVAL) {
+            return;
+        }
+        *last_render = Some(Instant::now());
+
+        let line = format_status(
+            self.current.load(Ordering::Relaxed),
+            self.total,
+            &self.message.lock().unwrap(),
+        );
+
+        let mut stderr = stderr();
+        let _ = write!(stderr, "\r\x1b[2K{ANSI_ITALIC}{line}{ANSI_RESET}");
+        let _ = stderr.flush();
+        RENDERED.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        clear();
+    }
+}
+
+fn is_active() -> bool {
+    ENABLED.load(Ordering::Relaxed) && stderr().is_terminal()
+}
+
+fn format_status(current: u64, total: u64, message: &str) -> String {
+    let status = if total == 0 {
+        format!("{current}")
+    } else {
+        #[allow(clippy::cast_precision_loss)]
+        let percent = current.min(total) as f64 / total as f64 * 100.0;
+        format!("{percent:5.1}% ({current}/{total})")
+    };
+
+    if message.is_empty() {
+        status
+    } else {
+        format!("{status} {message}")
+    }
+}
+
+/// Enables or disables rendering. The runner disables progress while benching.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Clears the status line, if one was rendered.
+pub fn clear() {
+    if RENDERED.swap(false, Ordering::Relaxed) {
+        let mut stderr = stderr();
+        let _ = write!(stderr, "\r\x1b[2K");
+        let _ = stderr.flush();
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_status;
+
+    #[test]
+    fn formats_status() {
+        assert_eq!(format_status(250, 1000, ""), " 25.0% (250/1000)");
+        assert_eq!(
+            format_status(1000, 1000, "range 2/2"),
+            "100.0% (1000/1000) range 2/2"
+        );
+        assert_eq!(format_status(42, 0, "seeds"), "42 seeds");
+    }
+}
@@ -2,6 +2,7 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::report::Status;
 use crate::Day;
 
 static MARKER: &str = "<!--- benchmarking table --->";
@@ -21,11 +22,60 @@ impl From<std::io::Error> for Error {
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
     pub total_nanos: f64,
 }
 
+/// Timing summary of a single part, as reported by the runner.
+/// The spread fields are only present for benched runs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartTiming {
+    pub status: Status,
+    pub median: String,
+    /// The median, in nanoseconds.
+    pub nanos: f64,
+    pub min: Option<String>,
+    pub p95: Option<String>,
+    pub max: Option<String>,
+    pub std_dev: Option<String>,
+    /// Heap usage, only present for runs with `--alloc`.
+    pub alloc: Option<String>,
+}
+
+impl PartTiming {
+    fn alloc_cell(&self) -> String {
+        format!("`{}`", self.alloc.as_deref().unwrap_or("-"))
+    }
+
+    fn to_cell(&self) -> String {
+        match self.status {
+            Status::TimedOut => return "`timed out`".into(),
+            Status::Panicked => return "`panicked`".into(),
+            Status::Failed => return "`failed`".into(),
+            _ => {}
+        }
+
+        match (&self.min, &self.p95, &self.max, &self.std_dev) {
+            (Some(min), Some(p95), Some(max), Some(std_dev)) => format!(
+                "`{}`<br><sub>min {min} · p95 {p95} · max {max} · σ {std_dev}</sub>",
+                self.median
+            ),
+            _ => format!("`{}`", self.median),
+        }
+    }
+}
+
+impl From<&str> for PartTiming {
+    fn from(median: &str) -> Self {
+        Self {
+            median: median.into(),
+            ..Self::default()
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
@@ -61,23 +111,56 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    // only show the parse and allocation columns if at least one solution reported them.
+    let has_parse = timings.iter().any(|t| t.parse.is_some());
+    let has_alloc = timings.iter().any(|t| {
+        [&t.parse, &t.part_1, &t.part_2]
+            .iter()
+            .any(|p| p.as_ref().is_some_and(|p| p.alloc.is_some()))
+    });
+
+    let mut columns = vec!["Day"];
+    if has_parse {
+        columns.push("Parse");
+    }
+    columns.extend(["Part 1", "Part 2"]);
+    if has_alloc {
+        if has_parse {
+            columns.push("Parse Allocs");
+        }
+        columns.extend(["Part 1 Allocs", "Part 2 Allocs"]);
+    }
+
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", columns.join(" | ")),
+        format!("| {}  |", vec![":---:"; columns.len()].join(" | ")),
     ];
 
     for timing in timings {
         let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        let parts = if has_parse {
+            vec![timing.parse, timing.part_1, timing.part_2]
+        } else {
+            vec![timing.part_1, timing.part_2]
+        };
+
+        let mut cells = vec![format!("[Day {}]({})", timing.day.into_inner(), path)];
+        cells.extend(
+            parts
+                .iter()
+                .map(|t| t.as_ref().map_or_else(|| "`-`".into(), PartTiming::to_cell)),
+        );
+        if has_alloc {
+            cells.extend(parts.iter().map(|t| {
+                t.as_ref()
+                    .map_or_else(|| "`-`".into(), PartTiming::alloc_cell)
+            }));
+        }
+
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
@@ -104,25 +187,29 @@ pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
+    use super::{update_content, PartTiming, Timings, MARKER};
     use crate::day;
+    use crate::template::report::Status;
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
             },
             Timings {
                 day: day!(2),
+                parse: None,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
             },
             Timings {
                 day: day!(4),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
@@ -183,4 +270,71 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmark_spread() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        let timings = vec![Timings {
+            day: day!(1),
+            parse: None,
+            part_1: Some(PartTiming {
+                status: Status::Solved,
+                median: "10ms".into(),
+                nanos: 1e+7,
+                min: Some("9ms".into()),
+                p95: Some("12ms".into()),
+                max: Some("13ms".into()),
+                std_dev: Some("1ms".into()),
+                alloc: None,
+            }),
+            part_2: None,
+            total_nanos: 1e+7,
+        }];
+        update_content(&mut s, timings, 10.0).unwrap();
+        assert!(s.contains(
+            "| [Day 1](./src/bin/01.rs) | `10ms`<br><sub>min 9ms · p95 12ms · max 13ms · σ 1ms</sub> | `-` |"
+        ));
+    }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings[0].parse = Some("5ms".into());
+        update_content(&mut s, timings, 195.0).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `5ms` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `-` | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn format_benchmarks_with_alloc() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings[0].part_1.as_mut().unwrap().alloc = Some("12 allocs, 2.0 KiB, peak 800 B".into());
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Part 1 Allocs | Part 2 Allocs |"));
+        assert!(s.contains("| :---: | :---: | :---: | :---: | :---:  |"));
+        assert!(s.contains(
+            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` | `12 allocs, 2.0 KiB, peak 800 B` | `-` |"
+        ));
+    }
+
+    #[test]
+    fn format_timed_out_parts() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings[1].part_2.as_mut().unwrap().status = Status::TimedOut;
+        update_content(&mut s, timings, 150.0).unwrap();
+        assert!(s.contains("| [Day 2](./src/bin/02.rs) | `30ms` | `timed out` |"));
+    }
+
+    #[test]
+    fn format_panicked_parts() {
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings[0].part_1.as_mut().unwrap().status = Status::Panicked;
+        update_content(&mut s, timings, 150.0).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/01.rs) | `panicked` | `20ms` |"));
+    }
 }
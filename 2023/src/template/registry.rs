@@ -0,0 +1,14 @@
+/// Solutions compiled into the main binary, so `all --in-process` can run every day without spawning a
+/// process per day. The list itself is generated by `build.rs` when the `registry` feature is enabled.
+use crate::Day;
+
+pub struct Solution {
+    pub day: Day,
+    /// Solves both parts for the given input, see the `solution!` macro.
+    pub run: fn(&str),
+}
+
+#[must_use]
+pub fn find(solutions: &[Solution], day: Day) -> Option<&Solution> {
+    solutions.iter().find(|s| s.day == day)
+}
@@ -0,0 +1,211 @@
+/// Machine-readable results that solution binaries write when invoked with `--report <path>`.
+/// Each parse step and part appends one JSON line to the file, so callers such as `all` never have to
+/// scrape the human-readable output, which solutions are free to add to.
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::alloc::AllocStats;
+use crate::template::answers::Verdict;
+use crate::template::runner::Summary;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    #[default]
+    Solved,
+    Unsolved,
+    TimedOut,
+    Panicked,
+    /// The part returned an error.
+    Failed,
+}
+
+/// Timing of a part, in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimingReport {
+    pub median: u64,
+    pub mean: u64,
+    pub min: u64,
+    pub max: u64,
+    pub std_dev: u64,
+    pub p95: u64,
+    pub samples: u64,
+    pub outliers: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartReport {
+    /// One of `parse`, `1` or `2`.
+    pub part: String,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub timing: TimingReport,
+    pub alloc: Option<AllocStats>,
+    /// Whether the answer matches the accepted one, see [`crate::template::answers`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<Verdict>,
+    /// Why the part failed, e.g. the panic message and location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PartReport {
+    /// Human-readable name of the part, e.g. `Part 1`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        label(&self.part)
+    }
+}
+
+/// Human-readable name of a part identifier, e.g. `Parse` for `parse`.
+#[must_use]
+pub fn label(part: &str) -> String {
+    if part == "parse" {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+impl From<&Summary> for TimingReport {
+    fn from(summary: &Summary) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        let nanos = |d: std::time::Duration| d.as_nanos() as u64;
+
+        Self {
+            median: nanos(summary.median),
+            mean: nanos(summary.mean),
+            min: nanos(summary.min),
+            max: nanos(summary.max),
+            std_dev: nanos(summary.std_dev),
+            p95: nanos(summary.p95),
+            #[allow(clippy::cast_possible_truncation)]
+            samples: summary.samples as u64,
+            outliers: summary.outliers,
+        }
+    }
+}
+
+/// Reports emitted while [`capture`] is running, collected instead of written to a file.
+static CAPTURED: Mutex<Option<Vec<PartReport>>> = Mutex::new(None);
+
+/// Runs `func` and returns all reports emitted by it. Used to run solutions in-process.
+pub fn capture(func: impl FnOnce()) -> Vec<PartReport> {
+    *CAPTURED.lock().unwrap() = Some(vec![]);
+    func();
+    CAPTURED.lock().unwrap().take().unwrap_or_default()
+}
+
+/// Appends `report` to the file passed via `--report`, if any.
+pub fn emit(report: &PartReport) {
+    if let Some(captured) = CAPTURED.lock().unwrap().as_mut() {
+        captured.push(report.clone());
+        return;
+    }
+
+    let args: Vec<String> = env::args().collect();
+
+    let Some(path) = args
+        .iter()
+        .position(|x| x == "--report")
+        .and_then(|i| args.get(i + 1))
+    else {
+        return;
+    };
+
+    if let Err(e) = append(path, report) {
+        eprintln!("Failed to write report to \"{path}\": {e}");
+    }
+}
+
+fn append(path: &str, report: &PartReport) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(report)?)
+}
+
+/// Reads all reports written to `path`. A missing file yields no reports.
+pub fn read(path: &Path) -> io::Result<Vec<PartReport>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    parse(&fs::read_to_string(path)?)
+}
+
+fn parse(s: &str) -> io::Result<Vec<PartReport>> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(io::Error::from))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{capture, emit, parse, PartReport, Status, TimingReport};
+    use crate::template::alloc::AllocStats;
+    use crate::template::answers::Verdict;
+
+    fn report(part: &str) -> PartReport {
+        PartReport {
+            part: part.into(),
+            status: Status::Solved,
+            answer: Some("New min:\n42".into()),
+            timing: TimingReport {
+                median: 1500,
+                mean: 1600,
+                min: 1000,
+                max: 3000,
+                std_dev: 200,
+                p95: 2500,
+                samples: 100,
+                outliers: 3,
+            },
+            alloc: Some(AllocStats {
+                allocations: 2,
+                bytes: 64,
+                peak: 32,
+            }),
+            verdict: Some(Verdict::Verified),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn roundtrips_reports() {
+        let reports = vec![report("parse"), report("1")];
+        let content = reports
+            .iter()
+            .map(|r| serde_json::to_string(r).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(parse(&content).unwrap(), reports);
+    }
+
+    #[test]
+    fn serializes_status_in_snake_case() {
+        let json = serde_json::to_string(&report("2")).unwrap();
+        assert!(json.contains(r#""status":"solved""#));
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_on_malformed_line() {
+        parse("Part 1: 42 (1.0ms)").unwrap();
+    }
+
+    #[test]
+    fn captures_emitted_reports() {
+        let reports = capture(|| {
+            emit(&report("1"));
+            emit(&report("2"));
+        });
+        assert_eq!(reports, vec![report("1"), report("2")]);
+    }
+}
@@ -1,103 +1,592 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
+use crate::template::alloc::{self, AllocStats};
+use crate::template::answer::Answer;
+use crate::template::answers::{self, Verdict};
+use crate::template::panics::{self, Panic};
+use crate::template::report::{self, PartReport, Status};
+use crate::template::{aoc_client, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{progress, watchdog};
 use crate::Day;
 use std::fmt::Display;
+use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Return types of solution parts that the runner understands, for any `T` that converts into an [`Answer`].
+/// For `Option<T>`, `None` means that the part is not solved yet. For `Result<T, E>`, an error means that
+/// the part failed.
+pub trait PartOutput {
+    /// The answer, `Ok(None)` if the part is not solved yet, or the error if it failed.
+    fn answer(&self) -> Result<Option<Answer>, String>;
+}
+
+impl<T: Clone + Into<Answer>> PartOutput for Option<T> {
+    fn answer(&self) -> Result<Option<Answer>, String> {
+        Ok(self.clone().map(Into::into))
+    }
+}
+
+impl<T: Clone + Into<Answer>, E: Display> PartOutput for Result<T, E> {
+    fn answer(&self) -> Result<Option<Answer>, String> {
+        // the alternate form includes the whole chain for anyhow-style errors, e.g. `bad card: invalid digit`.
+        self.as_ref()
+            .map(|answer| Some(answer.clone().into()))
+            .map_err(|e| format!("{e:#}"))
+    }
+}
+
+pub fn run_part<I: Clone, R: PartOutput>(func: impl Fn(I) -> R, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (output, summary, alloc) = run_timed(func, input, &part.to_string(), |output| {
+        if let Ok(answer) = output.answer() {
+            print_result(&answer, &part_str, "");
+        }
+    });
+
+    let report = |status, answer, verdict, error| PartReport {
+        part: part.to_string(),
+        status,
+        answer,
+        timing: (&summary).into(),
+        alloc,
+        verdict,
+        error,
+    };
+
+    let output = match output {
+        Ok(output) => output,
+        Err(panic) => {
+            print_panic(&panic, &part_str);
+            report::emit(&report(
+                Status::Panicked,
+                None,
+                None,
+                Some(panic.to_string()),
+            ));
+            return;
+        }
+    };
+
+    let answer = match output.answer() {
+        Ok(answer) => answer,
+        Err(error) => {
+            print_failure(&error, &part_str);
+            report::emit(&report(Status::Failed, None, None, Some(error)));
+            return;
+        }
+    };
+
+    // drawings are reported and verified as the letters they draw, like they are printed.
+    let value = answer
+        .as_ref()
+        .map(|answer| answer.submission().unwrap_or_else(|| answer.to_string()));
+    let verdict = value
+        .as_ref()
+        .map(|value| answers::verify(day, part, value));
+
+    let duration_str = format!(
+        "{}{}{}",
+        format_duration(&summary),
+        format_alloc(alloc),
+        format_verdict(verdict)
+    );
+    print_result(&answer, &part_str, &duration_str);
+
+    report::emit(&report(
+        if answer.is_some() {
+            Status::Solved
+        } else {
+            Status::Unsolved
+        },
+        value,
+        verdict,
+        None,
+    ));
+
+    if let Some(answer) = answer {
+        submit_result(&answer, day, part);
+    }
+}
+
+/// A named alternative implementation of a part.
+pub type Variant<'a, I, R> = (&'a str, fn(I) -> R);
+
+/// Run alternative implementations of a part on the same input and print their timings side by side.
+/// The first variant is the baseline that the speedup of the others is measured against.
+///
+/// # Panics
+///
+/// Panics if a variant panics or the variants do not all return the same answer.
+pub fn run_variants<I: Clone, R: PartOutput>(variants: &[Variant<I, R>], input: I, part: u8) {
+    println!("Part {part} variants:");
+
+    let mut results: Vec<(&str, R)> = vec![];
+    let mut baseline: Option<Duration> = None;
+
+    for &(name, func) in variants {
+        let label = format!("  {name}");
+
+        let (output, summary, alloc) =
+            run_timed(func, input.clone(), &part.to_string(), |output| {
+                if let Ok(answer) = output.answer() {
+                    print_result(&answer, &label, "");
+                }
+            });
+
+        let output = match output {
+            Ok(output) => output,
+            Err(panic) => {
+                print_panic(&panic, &label);
+                panic!("Part {part}: variant {name} panicked");
+            }
+        };
+
+        match output.answer() {
+            Ok(answer) => {
+                let speedup = baseline.map_or_else(String::new, |baseline| {
+                    format_speedup(&baseline, &summary.median, variants[0].0)
+                });
+                let duration_str = format!(
+                    "{}{}{speedup}",
+                    format_duration(&summary),
+                    format_alloc(alloc)
+                );
+                print_result(&answer, &label, &duration_str);
+            }
+            Err(error) => print_failure(&error, &label),
+        }
+
+        baseline.get_or_insert(summary.median);
+        results.push((name, output));
+    }
+
+    if let Some(mismatch) = variant_mismatch(&results, part) {
+        panic!("{mismatch}");
+    }
+}
+
+/// Run alternative implementations of a part without timing them and check that they return the same answer.
+///
+/// # Panics
+///
+/// Panics if the variants do not all return the same answer.
+pub fn assert_variants_agree<I: Clone, R: PartOutput>(
+    variants: &[Variant<I, R>],
+    input: I,
+    part: u8,
+) {
+    let results: Vec<_> = variants
+        .iter()
+        .map(|&(name, func)| (name, func(input.clone())))
+        .collect();
+
+    if let Some(mismatch) = variant_mismatch(&results, part) {
+        panic!("{mismatch}");
+    }
+}
+
+/// Describes the first variant whose answer differs from the baseline's, if any.
+fn variant_mismatch<R: PartOutput>(results: &[(&str, R)], part: u8) -> Option<String> {
+    let (baseline, expected) = results.first()?;
+    let describe = |output: &R| match output.answer() {
+        Ok(Some(answer)) => answer.to_string(),
+        Ok(None) => "no answer".into(),
+        Err(error) => format!("error \"{error}\""),
+    };
+
+    results
+        .iter()
+        .find(|(_, output)| output.answer() != expected.answer())
+        .map(|(name, output)| {
+            format!(
+                "Part {part}: variant {name} returned {}, but {baseline} returned {}",
+                describe(output),
+                describe(expected)
+            )
+        })
+}
+
+fn format_speedup(baseline: &Duration, current: &Duration, baseline_name: &str) -> String {
+    if current.is_zero() {
+        return String::new();
+    }
+
+    let ratio = baseline.as_secs_f64() / current.as_secs_f64();
+    format!(" {ANSI_ITALIC}{ratio:.2}× vs {baseline_name}{ANSI_RESET}")
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Run the parse step of a solution and return its output, so it can be shared by both parts.
+/// Returns `None` if parsing panicked.
+pub fn run_parse<I: Clone, T>(func: impl Fn(I) -> T, input: I) -> Option<T> {
+    let (result, summary, alloc) = run_timed(func, input, "parse", |_| print!("Parse: ✔"));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    match &result {
+        Ok(_) => {
+            print!("\r");
+            println!(
+                "Parse: ✔{}{}",
+                format_duration(&summary),
+                format_alloc(alloc)
+            );
+        }
+        Err(panic) => print_panic(panic, "Parse"),
     }
+
+    report::emit(&PartReport {
+        part: "parse".into(),
+        status: if result.is_ok() {
+            Status::Solved
+        } else {
+            Status::Panicked
+        },
+        answer: None,
+        timing: (&summary).into(),
+        alloc,
+        verdict: None,
+        error: result.as_ref().err().map(ToString::to_string),
+    });
+
+    result.ok()
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched as configured by [`BenchConfig`].
+///
+/// With `--alloc`, heap usage of the first execution is tracked as well.
+/// The first execution is subject to the watchdog's time limits, benching is not.
+/// A panic in the first execution is caught and returned, the part is not benched then. A panic while benching
+/// is caught and returned as well.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    part: &str,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+) -> (Result<T, Panic>, Summary, Option<AllocStats>) {
+    let execute = || {
+        watchdog::enter(part);
+        let timer = Instant::now();
+        let result = panics::catch(|| func(input.clone()));
+        let elapsed = timer.elapsed();
+        watchdog::leave();
+        (result, elapsed)
+    };
+
+    let ((result, base_time), alloc) = if env::args().any(|x| x == "--alloc") {
+        let (run, stats) = alloc::track(execute);
+        (run, Some(stats))
+    } else {
+        (execute(), None)
+    };
+
+    progress::clear();
+
+    let result = match result {
+        Ok(result) => result,
+        Err(panic) => return (Err(panic), Summary::single(base_time), alloc),
+    };
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let summary = if std::env::args().any(|x| x == "--time") {
+        // a part can still panic while benching, e.g. on a later execution only.
+        match panics::catch(|| bench(func, input, &base_time, &BenchConfig::from_args())) {
+            Ok(summary) => summary,
+            Err(panic) => {
+                progress::set_enabled(true);
+                return (Err(panic), Summary::single(base_time), alloc);
+            }
+        }
     } else {
-        (base_time, 1)
+        Summary::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (Ok(result), summary, alloc)
+}
+
+/// Controls how long and how often a part is benched with `--time`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchConfig {
+    /// Approximate execution time spent taking samples (`--bench-time <secs>`).
+    pub budget: Duration,
+    /// Samples taken at least, even if this exceeds the budget (`--min-samples <n>`).
+    pub min_samples: u128,
+    /// Samples taken at most, even if the budget is not used up (`--max-samples <n>`).
+    pub max_samples: u128,
+    /// Execution time spent before samples are taken (`--warmup <secs>`).
+    pub warmup: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+            warmup: Duration::ZERO,
+        }
+    }
+}
+
+impl BenchConfig {
+    fn from_args() -> Self {
+        Self::parse(&env::args().collect::<Vec<_>>())
+    }
+
+    /// Reads the bench flags from `args`, falling back to the defaults for missing or invalid values.
+    pub fn parse(args: &[String]) -> Self {
+        let value = |flag: &str| {
+            args.iter()
+                .position(|x| x == flag)
+                .and_then(|i| args.get(i + 1))
+        };
+        let secs = |flag: &str| {
+            value(flag)
+                .and_then(|x| x.parse::<f64>().ok())
+                .and_then(|x| Duration::try_from_secs_f64(x).ok())
+        };
+        let count = |flag: &str| value(flag).and_then(|x| x.parse::<u128>().ok());
+
+        Self::new(
+            secs("--bench-time"),
+            count("--min-samples"),
+            count("--max-samples"),
+            secs("--warmup"),
+        )
+    }
+
+    /// Builds a config from the given values, falling back to the defaults for missing ones.
+    /// At least one sample is taken, and `max_samples` is raised to `min_samples` if it is lower.
+    #[must_use]
+    pub fn new(
+        budget: Option<Duration>,
+        min_samples: Option<u128>,
+        max_samples: Option<u128>,
+        warmup: Option<Duration>,
+    ) -> Self {
+        let defaults = Self::default();
+        let min_samples = min_samples.unwrap_or(defaults.min_samples).max(1);
+
+        Self {
+            budget: budget.unwrap_or(defaults.budget),
+            min_samples,
+            max_samples: max_samples.unwrap_or(defaults.max_samples).max(min_samples),
+            warmup: warmup.unwrap_or(defaults.warmup),
+        }
+    }
+
+    /// Flags that pass this config on to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--bench-time".into(),
+            self.budget.as_secs_f64().to_string(),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+            "--warmup".into(),
+            self.warmup.as_secs_f64().to_string(),
+        ]
+    }
+
+    /// Number of samples to take for a part whose first execution took `base_time`.
+    fn iterations(&self, base_time: &Duration) -> u128 {
+        (self.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+            .clamp(self.min_samples, self.max_samples)
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> Summary {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let bench_iterations = config.iterations(base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
+    // progress output would both skew the timings and clutter the output.
+    progress::set_enabled(false);
+
+    let warmup = Instant::now();
+    while warmup.elapsed() < config.warmup {
+        black_box(func(black_box(input.clone())));
+    }
+
     for _ in 0..bench_iterations {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
-        func(cloned);
+        // black_box keeps the optimizer from dropping work whose result is unused.
+        black_box(func(black_box(cloned)));
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    progress::set_enabled(true);
+
+    Summary::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Statistical summary of the samples collected while benching a part.
+/// Samples outside of the Tukey fences (1.5 × IQR beyond the quartiles) are
+/// discarded as outliers before the remaining statistics are computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub median: Duration,
+    pub mean: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    /// Number of samples taken, including rejected outliers.
+    pub samples: u128,
+    /// Number of samples rejected as outliers.
+    pub outliers: usize,
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+impl Summary {
+    /// Summary of a single, unbenched execution.
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            median: duration,
+            mean: duration,
+            min: duration,
+            max: duration,
+            std_dev: Duration::ZERO,
+            p95: duration,
+            samples: 1,
+            outliers: 0,
+        }
+    }
+
+    /// Computes the summary for a non-empty set of samples.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        if sorted.len() < 4 {
+            return Self::from_sorted(&sorted, samples.len(), 0);
+        }
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let fence = (q3 - q1) * 3 / 2;
+        let (low, high) = (q1.saturating_sub(fence), q3 + fence);
+
+        let kept: Vec<u128> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (low..=high).contains(x))
+            .collect();
+
+        Self::from_sorted(&kept, samples.len(), sorted.len() - kept.len())
+    }
+
+    fn from_sorted(sorted: &[u128], samples: usize, outliers: usize) -> Self {
+        let len = sorted.len() as u128;
+        let mean = sorted.iter().sum::<u128>() / len;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = sorted
+            .iter()
+            .map(|&x| (x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Self {
+            median: nanos(percentile(sorted, 50.0)),
+            mean: nanos(mean),
+            min: nanos(sorted[0]),
+            max: nanos(sorted[sorted.len() - 1]),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            p95: nanos(percentile(sorted, 95.0)),
+            samples: samples as u128,
+            outliers,
+        }
+    }
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn nanos(n: u128) -> Duration {
+    Duration::from_nanos(n as u64)
+}
+
+fn format_duration(summary: &Summary) -> String {
+    let Summary {
+        median,
+        min,
+        max,
+        std_dev,
+        p95,
+        samples,
+        outliers,
+        ..
+    } = summary;
+
+    if *samples == 1 {
+        format!(" ({median:.1?})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({median:.1?} @ {samples} samples) [min {min:.1?}, p95 {p95:.1?}, max {max:.1?}, σ {std_dev:.1?}, {outliers} outliers]"
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_alloc(alloc: Option<AllocStats>) -> String {
+    alloc.map_or_else(String::new, |stats| format!(" [alloc: {stats}]"))
+}
+
+fn format_verdict(verdict: Option<Verdict>) -> String {
+    match verdict {
+        Some(Verdict::Regressed) => " ✖ expected a different answer".into(),
+        Some(verdict) => format!(" {}", verdict.mark()),
+        None => String::new(),
+    }
+}
+
+fn print_failure(error: &str, part: &str) {
+    print!("\r");
+    println!("{part}: ⚠ {error}");
+}
+
+fn print_panic(panic: &Panic, part: &str) {
+    print!("\r");
+    println!("{part}: 💥 {panic}");
+}
+
+/// Prints an answer. Drawings of block letters are printed as the letters they show.
+fn print_result(result: &Option<Answer>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
-            if result.to_string().contains('\n') {
+            let letters = result.letters();
+
+            if letters.is_none() && result.is_multiline() {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -107,6 +596,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                     println!("{result}");
                 }
             } else {
+                let result = letters.unwrap_or_else(|| result.to_string());
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -129,12 +619,12 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+///  2. the answer can be submitted as a single line.
+fn submit_result(
+    result: &Answer,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<aoc_client::SubmitOutcome, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -157,11 +647,187 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let is_custom_input = args.contains(&"--input".into()) || args.contains(&"--example".into());
+    if is_custom_input && !args.contains(&"--force-submit".into()) {
+        eprintln!("Not submitting: this answer is for a custom input. Pass --force-submit to submit it anyway.");
+        return None;
+    }
+
+    let Some(answer) = result.submission() else {
+        eprintln!("Not submitting: multi-line answers can not be submitted as they are. Submit the letters they show instead.");
+        return None;
+    };
+
+    println!("Submitting result...");
+    let output = aoc_client::submit(day, part, &answer);
+
+    match &output {
+        Ok(aoc_client::SubmitOutcome::Correct) => match answers::record(day, part, &answer) {
+            Ok(()) => println!("Recorded the answer as verified."),
+            Err(e) => eprintln!("Failed to record the answer: {e:?}"),
+        },
+        Ok(_) => {}
+        Err(e) => eprintln!("Failed to submit the answer: {e}"),
+    }
+
+    Some(output)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        format_duration, format_speedup, variant_mismatch, BenchConfig, PartOutput, Summary,
+    };
+    use crate::template::answer::Answer;
+    use std::fmt::Display;
+    use std::time::Duration;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_bench_config() {
+        let config = BenchConfig::parse(&args(&[
+            "--time",
+            "--bench-time",
+            "0.5",
+            "--min-samples",
+            "20",
+            "--warmup",
+            "0.1",
+        ]));
+        assert_eq!(config.budget, Duration::from_millis(500));
+        assert_eq!(config.min_samples, 20);
+        assert_eq!(config.max_samples, 10000);
+        assert_eq!(config.warmup, Duration::from_millis(100));
+        assert_eq!(BenchConfig::parse(&config.to_args()), config);
+    }
+
+    #[test]
+    fn keeps_max_samples_above_min_samples() {
+        let config = BenchConfig::parse(&args(&["--min-samples", "50", "--max-samples", "5"]));
+        assert_eq!(config.min_samples, 50);
+        assert_eq!(config.max_samples, 50);
+
+        let config = BenchConfig::new(None, Some(0), Some(0), None);
+        assert_eq!(config.min_samples, 1);
+        assert_eq!(config.max_samples, 1);
+    }
+
+    #[test]
+    fn clamps_iterations() {
+        let config = BenchConfig::default();
+        assert_eq!(config.iterations(&Duration::from_millis(10)), 100);
+        assert_eq!(config.iterations(&Duration::from_secs(5)), 10);
+        assert_eq!(config.iterations(&Duration::from_nanos(1)), 10000);
+    }
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_micros).collect()
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    #[test]
+    fn summarizes_samples() {
+        let summary = Summary::from_samples(&micros(&[5, 1, 4, 2, 3]));
+        assert_eq!(summary.median, Duration::from_micros(3));
+        assert_eq!(summary.mean, Duration::from_micros(3));
+        assert_eq!(summary.min, Duration::from_micros(1));
+        assert_eq!(summary.max, Duration::from_micros(5));
+        assert_eq!(summary.p95, Duration::from_micros(5));
+        assert_eq!(summary.std_dev, Duration::from_nanos(1414));
+        assert_eq!(summary.samples, 5);
+        assert_eq!(summary.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let summary = Summary::from_samples(&micros(&[10, 11, 10, 12, 11, 10, 500]));
+        assert_eq!(summary.outliers, 1);
+        assert_eq!(summary.samples, 7);
+        assert_eq!(summary.max, Duration::from_micros(12));
+        assert_eq!(summary.median, Duration::from_micros(10));
+    }
+
+    #[test]
+    fn formats_single_run() {
+        let summary = Summary::single(Duration::from_micros(1500));
+        assert_eq!(format_duration(&summary), " (1.5ms)");
+    }
+
+    #[test]
+    fn formats_benched_run() {
+        let summary = Summary::from_samples(&micros(&[5, 1, 4, 2, 3]));
+        assert_eq!(
+            format_duration(&summary),
+            " (3.0µs @ 5 samples) [min 1.0µs, p95 5.0µs, max 5.0µs, σ 1.4µs, 0 outliers]"
+        );
+    }
+
+    #[test]
+    fn finds_variant_mismatch() {
+        assert_eq!(
+            variant_mismatch(&[("naive", Some(46)), ("fast", Some(46))], 2),
+            None
+        );
+        assert_eq!(
+            variant_mismatch(&[("naive", Some(46)), ("fast", Some(45))], 2),
+            Some("Part 2: variant fast returned 45, but naive returned 46".into())
+        );
+        assert_eq!(
+            variant_mismatch(&[("naive", Some(46)), ("fast", None)], 2),
+            Some("Part 2: variant fast returned no answer, but naive returned 46".into())
+        );
+    }
+
+    #[test]
+    fn finds_variant_mismatch_with_errors() {
+        let results: [(&str, Result<u32, String>); 2] =
+            [("naive", Ok(46)), ("fast", Err("overflow".into()))];
+        assert_eq!(
+            variant_mismatch(&results, 2),
+            Some("Part 2: variant fast returned error \"overflow\", but naive returned 46".into())
+        );
+    }
+
+    /// An error that prints its cause in the alternate form, like `anyhow::Error`.
+    struct ChainedError;
+
+    impl Display for ChainedError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if f.alternate() {
+                write!(f, "bad card: invalid digit")
+            } else {
+                write!(f, "bad card")
+            }
+        }
+    }
+
+    #[test]
+    fn reads_answers_of_outputs() {
+        assert_eq!(Some(42).answer(), Ok(Some(Answer::Unsigned(42))));
+        assert_eq!(None::<u32>.answer(), Ok(None));
+        assert_eq!(
+            Ok::<&str, String>("abc").answer(),
+            Ok(Some(Answer::Text("abc".into())))
+        );
+        assert_eq!(
+            Err::<u32, _>(ChainedError).answer(),
+            Err("bad card: invalid digit".into())
+        );
+    }
+
+    #[test]
+    fn formats_speedup() {
+        let speedup = format_speedup(
+            &Duration::from_millis(30),
+            &Duration::from_millis(2),
+            "naive",
+        );
+        assert!(speedup.contains("15.00× vs naive"));
+        assert_eq!(
+            format_speedup(&Duration::from_millis(30), &Duration::ZERO, "naive"),
+            ""
+        );
+    }
 }
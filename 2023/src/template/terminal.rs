@@ -0,0 +1,335 @@
+/// Renders puzzle descriptions from `data/puzzles` in the terminal: headings and emphasis in bold, code in
+/// italic, example blocks in a box and paragraphs wrapped to the terminal width. Long output goes through a pager.
+use std::{
+    env,
+    io::{stdout, IsTerminal, Write},
+    process::{Command, Stdio},
+};
+
+use terminal_size::{terminal_size, Width};
+
+use crate::template::{markdown, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Paragraphs are not wrapped wider than this, even in wide terminals.
+const MAX_WIDTH: usize = 100;
+
+/// Width to wrap text at: the width of the terminal, at most [`MAX_WIDTH`]. `COLUMNS` overrides it, and
+/// without a terminal, e.g. when piped, it defaults to 80.
+#[must_use]
+pub fn width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .or_else(|| terminal_size().map(|(Width(width), _)| usize::from(width)))
+        .unwrap_or(80)
+        .clamp(20, MAX_WIDTH)
+}
+
+/// Renders Markdown as written by [`markdown::from_html`] with ANSI styling, wrapping text at `width`.
+#[must_use]
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut code: Option<Vec<&str>> = None;
+    let mut fence = None;
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<String>| {
+        if !paragraph.is_empty() {
+            blocks.push(render_paragraph(paragraph, width));
+            paragraph.clear();
+        }
+    };
+
+    for line in markdown.lines() {
+        let was_open = fence.is_some();
+        fence = markdown::next_fence(fence, line);
+
+        match (was_open, fence.is_some()) {
+            (false, true) => {
+                flush(&mut paragraph, &mut blocks);
+                code = Some(vec![]);
+            }
+            (true, true) => code.get_or_insert_with(Vec::new).push(line),
+            (true, false) => blocks.push(code_box(&code.take().unwrap_or_default())),
+            (false, false) => {
+                if line.trim().is_empty() {
+                    flush(&mut paragraph, &mut blocks);
+                } else if let Some(heading) = heading(line) {
+                    flush(&mut paragraph, &mut blocks);
+                    blocks.push(format!("{ANSI_BOLD}{}{ANSI_RESET}", inline(heading)));
+                } else {
+                    paragraph.push(line);
+                }
+            }
+        }
+    }
+
+    if let Some(code) = code {
+        blocks.push(code_box(&code));
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    format!("{}\n", blocks.join("\n\n"))
+}
+
+/// Prints text, through `$PAGER` (or `less`) if stdout is a terminal.
+pub fn page(text: &str) {
+    if !stdout().is_terminal() {
+        print!("{text}");
+        return;
+    }
+
+    // `-F` makes less quit right away if the text fits on one screen, `-R` keeps the styling.
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less -FRX".into());
+    let mut parts = pager.split_whitespace();
+
+    let child = parts.next().and_then(|program| {
+        Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .spawn()
+            .ok()
+    });
+
+    match child {
+        Some(mut child) => {
+            if let Some(mut stdin) = child.stdin.take() {
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            let _ = child.wait();
+        }
+        None => print!("{text}"),
+    }
+}
+
+fn heading(line: &str) -> Option<&str> {
+    let text = line.trim_start_matches('#');
+    (text.len() < line.len() && text.starts_with(' ')).then(|| text.trim())
+}
+
+/// Renders the lines of a paragraph. List items start a new line and wrap with a hanging indent.
+fn render_paragraph(lines: &[&str], width: usize) -> String {
+    let mut items: Vec<(String, String)> = vec![];
+
+    for line in lines {
+        let indent = line.len() - line.trim_start().len();
+        let text = line.trim_start();
+
+        match list_marker(text) {
+            Some(marker) => items.push((
+                format!("{}{marker}", " ".repeat(indent)),
+                text[marker.len()..].to_string(),
+            )),
+            None => match items.last_mut() {
+                Some((_, item)) => {
+                    item.push(' ');
+                    item.push_str(text);
+                }
+                None => items.push((String::new(), text.to_string())),
+            },
+        }
+    }
+
+    items
+        .iter()
+        .map(|(marker, text)| {
+            let indent = " ".repeat(marker.chars().count());
+            wrap(&inline(text), width, marker, &indent)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The marker of a list item, e.g. `- ` or `2. `.
+fn list_marker(text: &str) -> Option<&str> {
+    if text.starts_with("- ") || text.starts_with("* ") {
+        return Some(&text[..2]);
+    }
+
+    let digits = text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    (digits > 0 && text[digits..].starts_with(". ")).then(|| &text[..digits + 2])
+}
+
+/// Renders inline Markdown: emphasis in bold, code spans in italic, links as their text.
+fn inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut rendered = String::new();
+    let mut bold = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if i + 1 < chars.len() => {
+                rendered.push(chars[i + 1]);
+                i += 2;
+            }
+            '`' => {
+                let ticks = chars[i..].iter().take_while(|&&c| c == '`').count();
+                let fence: String = "`".repeat(ticks);
+                let rest: String = chars[i + ticks..].iter().collect();
+
+                match rest.find(&fence) {
+                    Some(end) => {
+                        let code = rest[..end].trim_matches(' ');
+                        rendered.push_str(&format!("{ANSI_ITALIC}{code}{ANSI_RESET}"));
+                        if bold {
+                            rendered.push_str(ANSI_BOLD);
+                        }
+                        i += ticks + rest[..end].chars().count() + ticks;
+                    }
+                    None => {
+                        rendered.push_str(&fence);
+                        i += ticks;
+                    }
+                }
+            }
+            '*' => {
+                // `**` and `*` both render as bold.
+                i += chars[i..].iter().take_while(|&&c| c == '*').count();
+                bold = !bold;
+                rendered.push_str(if bold { ANSI_BOLD } else { ANSI_RESET });
+            }
+            '[' => {
+                let rest: String = chars[i + 1..].iter().collect();
+                let link = rest.find("](").and_then(|label_end| {
+                    let url_end = label_end + rest[label_end..].find(')')?;
+                    Some((label_end, url_end))
+                });
+
+                match link {
+                    Some((label_end, url_end)) => {
+                        rendered.push_str(&inline(&rest[..label_end]));
+                        if bold {
+                            rendered.push_str(ANSI_BOLD);
+                        }
+                        i += 1 + rest[..=url_end].chars().count();
+                    }
+                    None => {
+                        rendered.push('[');
+                        i += 1;
+                    }
+                }
+            }
+            c => {
+                rendered.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    if bold {
+        rendered.push_str(ANSI_RESET);
+    }
+    rendered
+}
+
+/// Wraps styled text at `width` visible characters. The first line starts with `first`, the others with `rest`.
+fn wrap(text: &str, width: usize, first: &str, rest: &str) -> String {
+    let mut lines: Vec<String> = vec![];
+    let mut line = first.to_string();
+    let mut line_width = visible_width(first);
+    let mut is_empty = true;
+
+    for word in text.split(' ').filter(|word| !word.is_empty()) {
+        let word_width = visible_width(word);
+
+        if !is_empty && line_width + 1 + word_width > width {
+            lines.push(line);
+            line = rest.to_string();
+            line_width = visible_width(rest);
+            is_empty = true;
+        }
+
+        if !is_empty {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+        is_empty = false;
+    }
+
+    lines.push(line);
+    lines.join("\n")
+}
+
+/// Width of text in the terminal, not counting ANSI escape sequences.
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+
+    for c in text.chars() {
+        match (in_escape, c) {
+            (false, '\x1b') => in_escape = true,
+            (true, 'm') => in_escape = false,
+            (false, _) => width += 1,
+            (true, _) => {}
+        }
+    }
+
+    width
+}
+
+/// Draws a box around the lines of a code block. Example blocks are never wrapped.
+fn code_box(lines: &[&str]) -> String {
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let border = "─".repeat(width + 2);
+
+    let mut rendered = vec![format!("┌{border}┐")];
+    for line in lines {
+        let padding = width - line.chars().count();
+        rendered.push(format!("│ {line}{} │", " ".repeat(padding)));
+    }
+    rendered.push(format!("└{border}┘"));
+    rendered.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{inline, render, visible_width, wrap};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    #[test]
+    fn renders_inline_styles() {
+        assert_eq!(
+            inline(r"a *b* `c` *`42`* \*d [e](https://x)"),
+            format!(
+                "a {ANSI_BOLD}b{ANSI_RESET} {ANSI_ITALIC}c{ANSI_RESET} {ANSI_BOLD}{ANSI_ITALIC}42{ANSI_RESET}{ANSI_BOLD}{ANSI_RESET} *d e"
+            )
+        );
+        assert_eq!(inline("`` a`b ``"), format!("{ANSI_ITALIC}a`b{ANSI_RESET}"));
+    }
+
+    #[test]
+    fn wraps_by_visible_width() {
+        let text = format!("aaa {ANSI_BOLD}bbb{ANSI_RESET} ccc ddd");
+        assert_eq!(visible_width(&text), 15);
+        assert_eq!(
+            wrap(&text, 9, "- ", "  "),
+            format!("- aaa {ANSI_BOLD}bbb{ANSI_RESET}\n  ccc ddd")
+        );
+        assert_eq!(wrap("toolongword x", 5, "", ""), "toolongword\nx");
+    }
+
+    #[test]
+    fn renders_puzzles() {
+        let rendered = render(include_str!("../../data/fixtures/puzzle.md"), 40);
+        let plain = rendered
+            .replace(ANSI_BOLD, "")
+            .replace(ANSI_ITALIC, "")
+            .replace(ANSI_RESET, "");
+
+        assert!(rendered.starts_with(&format!(
+            "{ANSI_BOLD}--- Day 7: Lantern Sorting ---{ANSI_RESET}\n\n"
+        )));
+        assert!(plain.contains(
+            "┌─────────────┐\n│ 3 red       │\n│ 11 blue     │\n│ 7 <unknown> │\n└─────────────┘"
+        ));
+        assert!(plain.contains("\n1. Pair lanterns of the same color.\n2. Leftover lanterns pair with the\n   brightest one.\n   - Unless it is red.\n"));
+        assert!(plain.lines().all(|line| line.chars().count() <= 40));
+    }
+}
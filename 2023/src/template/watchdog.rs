@@ -0,0 +1,153 @@
+/// Enforces the `--timeout <secs>` (whole day) and `--part-timeout <secs>` (single part) limits inside a
+/// solution binary. A background thread watches the part that is currently executing and, once a limit is
+/// exceeded, reports that part as timed out and terminates the process.
+/// The day limit covers the whole run including benching, the part limit only a part's first execution.
+use std::{
+    env, process,
+    sync::{Mutex, OnceLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::progress;
+use crate::template::report::{self, PartReport, Status};
+use crate::template::runner::Summary;
+
+/// Exit code of a solution binary that was terminated by the watchdog, mirroring `timeout(1)`.
+pub const EXIT_CODE: i32 = 124;
+
+static POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Limits {
+    pub day: Option<Duration>,
+    pub part: Option<Duration>,
+}
+
+impl Limits {
+    fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let secs = |flag: &str| {
+            args.iter()
+                .position(|x| x == flag)
+                .and_then(|i| args.get(i + 1))
+                .and_then(|x| x.parse::<f64>().ok())
+                .and_then(|x| Duration::try_from_secs_f64(x).ok())
+        };
+
+        Self {
+            day: secs("--timeout"),
+            part: secs("--part-timeout"),
+        }
+    }
+
+    /// Returns the limit that was exceeded, if any.
+    fn exceeded(&self, day_elapsed: Duration, part_elapsed: Duration) -> Option<Duration> {
+        match (self.day, self.part) {
+            (Some(day), _) if day_elapsed > day => Some(day),
+            (_, Some(part)) if part_elapsed > part => Some(part),
+            _ => None,
+        }
+    }
+}
+
+struct State {
+    limits: Limits,
+    started: Instant,
+    /// The last part that was entered, and when it started if it is still executing.
+    current: Mutex<Option<(String, Option<Instant>)>>,
+}
+
+static STATE: OnceLock<Option<State>> = OnceLock::new();
+
+fn state() -> Option<&'static State> {
+    STATE
+        .get_or_init(|| {
+            let limits = Limits::from_args();
+            if limits.day.is_none() && limits.part.is_none() {
+                return None;
+            }
+            thread::spawn(watch);
+            Some(State {
+                limits,
+                started: Instant::now(),
+                current: Mutex::new(None),
+            })
+        })
+        .as_ref()
+}
+
+/// Marks `part` as executing. Its execution counts towards the limits until [`leave`] is called.
+pub fn enter(part: &str) {
+    if let Some(state) = state() {
+        *state.current.lock().unwrap() = Some((part.into(), Some(Instant::now())));
+    }
+}
+
+/// Marks the current part's execution as done.
+pub fn leave() {
+    if let Some(state) = state() {
+        if let Some((_, started)) = state.current.lock().unwrap().as_mut() {
+            *started = None;
+        }
+    }
+}
+
+fn watch() {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let Some(state) = STATE.get().and_then(Option::as_ref) else {
+            continue;
+        };
+
+        let current = state.current.lock().unwrap();
+        let Some((part, part_started)) = current.as_ref() else {
+            continue;
+        };
+
+        let part_elapsed = part_started.map_or(Duration::ZERO, |t| t.elapsed());
+        let Some(limit) = state.limits.exceeded(state.started.elapsed(), part_elapsed) else {
+            continue;
+        };
+
+        progress::clear();
+        println!("\r{}: ⏱ timed out after {limit:.1?}", report::label(part));
+
+        report::emit(&PartReport {
+            part: part.clone(),
+            status: Status::TimedOut,
+            answer: None,
+            timing: (&Summary::single(part_elapsed)).into(),
+            alloc: None,
+            verdict: None,
+            error: None,
+        });
+
+        process::exit(EXIT_CODE);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Limits;
+    use std::time::Duration;
+
+    #[test]
+    fn checks_limits() {
+        let secs = Duration::from_secs;
+        let limits = Limits {
+            day: Some(secs(10)),
+            part: Some(secs(3)),
+        };
+        assert_eq!(limits.exceeded(secs(5), secs(2)), None);
+        assert_eq!(limits.exceeded(secs(5), secs(4)), Some(secs(3)));
+        assert_eq!(limits.exceeded(secs(11), secs(1)), Some(secs(10)));
+
+        let unlimited = Limits {
+            day: None,
+            part: None,
+        };
+        assert_eq!(unlimited.exceeded(secs(1000), secs(1000)), None);
+    }
+}